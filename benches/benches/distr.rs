@@ -95,6 +95,12 @@ fn bench(c: &mut Criterion<CyclesPerByte>) {
     distr_float!(g, "small_shape", f64, Gamma::new(0.1, 1.0).unwrap());
     g.finish();
 
+    let mut g = c.benchmark_group("chi");
+    distr_float!(g, "chi", f64, Chi::new(3.0).unwrap());
+    distr_float!(g, "maxwell_boltzmann", f64, MaxwellBoltzmann::new(1.0).unwrap());
+    distr_float!(g, "nakagami", f64, Nakagami::new(1.5, 3.0).unwrap());
+    g.finish();
+
     let mut g = c.benchmark_group("beta");
     distr_float!(g, "small_param", f64, Beta::new(0.1, 0.1).unwrap());
     distr_float!(g, "large_param_similar", f64, Beta::new(101., 95.).unwrap());
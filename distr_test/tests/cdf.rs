@@ -14,6 +14,7 @@ use statrs::distribution::DiscreteCDF;
 
 mod ks;
 use ks::test_continuous;
+use ks::test_continuous_approx;
 use ks::test_discrete;
 
 #[test]
@@ -40,6 +41,56 @@ fn normal() {
     }
 }
 
+#[test]
+fn half_normal() {
+    let parameters = [0.1, 1.0, 10.0];
+
+    for (seed, sigma) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::HalfNormal::new(sigma).unwrap();
+        let normal = statrs::distribution::Normal::new(0.0, sigma).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            if x < 0.0 {
+                0.0
+            } else {
+                normal.cdf(x) - normal.cdf(-x)
+            }
+        });
+    }
+}
+
+#[test]
+fn folded_normal() {
+    let parameters = [(0.0, 1.0), (1.0, 2.0), (-3.0, 1.0), (10.0, 0.5)];
+
+    for (seed, (mu, sigma)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::FoldedNormal::new(mu, sigma).unwrap();
+        let normal = statrs::distribution::Normal::new(mu, sigma).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            if x < 0.0 {
+                0.0
+            } else {
+                normal.cdf(x) - normal.cdf(-x)
+            }
+        });
+    }
+}
+
+#[test]
+fn half_cauchy() {
+    let parameters = [0.1, 1.0, 25.0];
+
+    for (seed, scale) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::HalfCauchy::new(scale).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            if x < 0.0 {
+                0.0
+            } else {
+                2.0 / f64::consts::PI * (x / scale).atan()
+            }
+        });
+    }
+}
+
 #[test]
 fn cauchy() {
     let parameters = [
@@ -162,6 +213,83 @@ fn weibull() {
     }
 }
 
+#[test]
+fn gompertz() {
+    fn cdf(x: f64, b: f64, eta: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        -(-eta * (b * x).exp_m1()).exp_m1()
+    }
+
+    let parameters = [(1.0, 1.0), (0.1, 0.01), (2.0, 10.0), (0.05, 1e-4)];
+
+    for (seed, (b, eta)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::Gompertz::new(b, eta).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, b, eta));
+    }
+}
+
+#[test]
+fn gompertz_makeham() {
+    fn cdf(x: f64, lambda: f64, b: f64, eta: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        -(-lambda * x - eta * (b * x).exp_m1()).exp_m1()
+    }
+
+    let parameters = [(0.0, 1.0, 1.0), (0.5, 1.0, 1.0), (0.002, 0.1, 0.01)];
+
+    for (seed, (lambda, b, eta)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::GompertzMakeham::new(lambda, b, eta).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, lambda, b, eta));
+    }
+}
+
+#[test]
+fn lomax() {
+    fn cdf(x: f64, lambda: f64, alpha: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        1.0 - (1.0 + x / lambda).powf(-alpha)
+    }
+
+    let parameters = [(1.0, 1.0), (1.0, 0.1), (0.1, 10.0), (100.0, 2.5)];
+
+    for (seed, (lambda, alpha)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::Lomax::new(lambda, alpha).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, lambda, alpha));
+    }
+}
+
+#[test]
+fn burr_xii() {
+    fn cdf(x: f64, lambda: f64, c: f64, k: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        1.0 - (1.0 + (x / lambda).powf(c)).powf(-k)
+    }
+
+    let parameters = [
+        (1.0, 1.0, 1.0),
+        (1.0, 2.0, 3.0),
+        (0.5, 0.5, 10.0),
+        (10.0, 5.0, 0.2),
+    ];
+
+    for (seed, (lambda, c, k)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::BurrXII::new(lambda, c, k).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, lambda, c, k));
+    }
+}
+
 #[test]
 fn gumbel() {
     fn cdf(x: f64, mu: f64, beta: f64) -> f64 {
@@ -247,6 +375,60 @@ fn chi_squared() {
         test_continuous(seed as u64, dist, |x| cdf(x, k));
     }
 }
+#[test]
+fn chi() {
+    fn cdf(x: f64, k: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        (x * x / 2.0).inc_gamma(k / 2.0)
+    }
+
+    let parameters = [0.1, 1.0, 2.0, 3.0, 10.0, 100.0];
+
+    for (seed, k) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::Chi::new(k).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, k));
+    }
+}
+
+#[test]
+fn maxwell_boltzmann() {
+    fn cdf(x: f64, a: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        (x * x / (2.0 * a * a)).inc_gamma(1.5)
+    }
+
+    let parameters = [0.1, 1.0, 2.0, 100.0];
+
+    for (seed, a) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::MaxwellBoltzmann::new(a).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, a));
+    }
+}
+
+#[test]
+fn nakagami() {
+    fn cdf(x: f64, m: f64, omega: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        (m * x * x / omega).inc_gamma(m)
+    }
+
+    let parameters = [(0.5, 1.0), (1.0, 1.0), (2.0, 0.5), (10.0, 100.0)];
+
+    for (seed, (m, omega)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::Nakagami::new(m, omega).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, m, omega));
+    }
+}
+
 #[test]
 fn studend_t() {
     fn cdf(x: f64, df: f64) -> f64 {
@@ -261,6 +443,31 @@ fn studend_t() {
         let dist = rand_distr::StudentT::new(df).unwrap();
         test_continuous(seed as u64, dist, |x| cdf(x, df));
     }
+
+    let parameters = [(1.0, -3.0, 0.5), (4.0, 10.0, 2.0), (30.0, 0.0, 100.0)];
+
+    for (seed, (df, mu, sigma)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::StudentT::with_location_scale(df, mu, sigma).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf((x - mu) / sigma, df));
+    }
+
+    let parameters = [(1.0, 1.0), (3.0, 2.5), (30.0, 0.1)];
+
+    for (seed, (df, sigma)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::HalfStudentT::new(df, sigma).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            if x < 0.0 {
+                0.0
+            } else {
+                2.0 * cdf(x / sigma, df) - 1.0
+            }
+        });
+    }
+
+    let dist = rand_distr::StudentT::with_location_scale(f64::INFINITY, 1.0, 2.0).unwrap();
+    test_continuous(3, dist, |x| {
+        statrs::distribution::Normal::new(1.0, 2.0).unwrap().cdf(x)
+    });
 }
 
 #[test]
@@ -341,6 +548,369 @@ fn inverse_gaussian() {
     }
 }
 
+#[test]
+fn ex_gaussian() {
+    fn cdf(x: f64, mu: f64, sigma: f64, lambda: f64) -> f64 {
+        let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+        let z = (x - mu) / sigma;
+        let ln_tail = -lambda * (x - mu)
+            + 0.5 * (lambda * sigma).powi(2)
+            + normal.cdf(z - lambda * sigma).ln();
+        normal.cdf(z) - ln_tail.exp()
+    }
+
+    let parameters = [
+        (0.0, 1.0, 1.0),
+        (300.0, 40.0, 0.01),
+        (-2.0, 0.5, 5.0),
+        (10.0, 3.0, 0.2),
+    ];
+
+    for (seed, (mu, sigma, lambda)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::ExGaussian::new(mu, sigma, lambda).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, mu, sigma, lambda));
+    }
+}
+
+#[test]
+fn variance_gamma() {
+    // With θ = 0 and ν = 1 this is a Laplace distribution with scale σ / sqrt(2)
+    fn cdf(x: f64, mu: f64, b: f64) -> f64 {
+        if x < mu {
+            0.5 * ((x - mu) / b).exp()
+        } else {
+            1.0 - 0.5 * (-(x - mu) / b).exp()
+        }
+    }
+
+    let parameters = [(0.0, 1.0), (1.0, 2.0), (-5.0, 0.1)];
+
+    for (seed, (mu, sigma)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::VarianceGamma::new(mu, 0.0, sigma, 1.0).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            cdf(x, mu, sigma / f64::consts::SQRT_2)
+        });
+    }
+}
+
+#[test]
+fn irwin_hall() {
+    // Σ_{k=0}^{⌊x⌋} (-1)^k C(n, k) (x - k)^n / n!, which is accurate in f64
+    // for small n when evaluated in the lower half
+    fn cdf(x: f64, n: u64) -> f64 {
+        let nf = n as f64;
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= nf {
+            return 1.0;
+        }
+        if x > 0.5 * nf {
+            return 1.0 - cdf(nf - x, n);
+        }
+        let mut sum = 0.0;
+        let mut binomial = 1.0;
+        for k in 0..=x.floor() as u64 {
+            let term = binomial * (x - k as f64).powi(n as i32);
+            sum += if k % 2 == 0 { term } else { -term };
+            binomial *= (n - k) as f64 / (k + 1) as f64;
+        }
+        sum / (1..=n).map(|j| j as f64).product::<f64>()
+    }
+
+    for (seed, n) in [1, 2, 3, 10, 40].into_iter().enumerate() {
+        let dist = rand_distr::IrwinHall::new(n).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, n));
+        let dist = rand_distr::Bates::new(n).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x * n as f64, n));
+    }
+
+    // For large n, compare against the normal limit. The leading Edgeworth
+    // term bounds the difference by about 0.03 / n.
+    for (seed, n) in [41, 100, 10000].into_iter().enumerate() {
+        let nf = n as f64;
+        let normal = statrs::distribution::Normal::new(0.5 * nf, (nf / 12.0).sqrt()).unwrap();
+        let dist = rand_distr::IrwinHall::new(n).unwrap();
+        test_continuous_approx(seed as u64, dist, |x| normal.cdf(x), 0.05 / nf);
+        let dist = rand_distr::Bates::new(n).unwrap();
+        test_continuous_approx(seed as u64, dist, |x| normal.cdf(x * nf), 0.05 / nf);
+    }
+}
+
+#[test]
+fn log_uniform() {
+    fn cdf(x: f64, a: f64, b: f64) -> f64 {
+        if x <= a {
+            0.0
+        } else if x >= b {
+            1.0
+        } else {
+            (x / a).ln() / (b / a).ln()
+        }
+    }
+
+    let parameters = [(1e-5, 1e-1), (1.0, 2.0), (0.5, 1e6)];
+
+    for (seed, (a, b)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::LogUniform::new(a, b).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, a, b));
+    }
+}
+
+#[test]
+fn log_transformed() {
+    // log-Cauchy
+    let dist = rand_distr::LogTransformed::new(rand_distr::Cauchy::new(1.0, 2.0).unwrap());
+    test_continuous(0, dist, |x| {
+        if x <= 0.0 {
+            0.0
+        } else {
+            0.5 + ((x.ln() - 1.0) / 2.0).atan() / f64::consts::PI
+        }
+    });
+
+    // The logarithm of an Exp(1) sample has a (minimum) Gumbel distribution
+    let dist = rand_distr::ExpTransformed::new(rand_distr::Exp1);
+    test_continuous(1, dist, |x| 1.0 - (-x.exp()).exp());
+}
+
+#[test]
+fn piecewise() {
+    fn constant_cdf(x: f64, b: &[f64], d: &[f64]) -> f64 {
+        let mut total = 0.0;
+        let mut below = 0.0;
+        for i in 0..d.len() {
+            let mass = d[i] * (b[i + 1] - b[i]);
+            total += mass;
+            below += d[i] * (x.clamp(b[i], b[i + 1]) - b[i]);
+        }
+        below / total
+    }
+
+    fn linear_cdf(x: f64, b: &[f64], d: &[f64]) -> f64 {
+        let mut total = 0.0;
+        let mut below = 0.0;
+        for i in 0..b.len() - 1 {
+            let w = b[i + 1] - b[i];
+            total += 0.5 * (d[i] + d[i + 1]) * w;
+            let t = (x.clamp(b[i], b[i + 1]) - b[i]) / w;
+            below += (d[i] * t + 0.5 * (d[i + 1] - d[i]) * t * t) * w;
+        }
+        below / total
+    }
+
+    let parameters: [(&[f64], &[f64], &[f64]); 3] = [
+        (&[0.0, 1.0], &[1.0], &[1.0, 1.0]),
+        (
+            &[-1.0, 0.0, 3.0, 10.0],
+            &[1.0, 0.0, 0.5],
+            &[0.0, 2.0, 0.0, 1.0],
+        ),
+        (
+            &[0.0, 0.1, 0.2, 0.5, 1.0, 2.0],
+            &[5.0, 0.5, 3.0, 1.0, 0.1],
+            &[1.0, 0.0, 0.0, 3.0, 1.0, 0.5],
+        ),
+    ];
+
+    for (seed, (b, dc, dl)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::PiecewiseConstant::new(b.to_vec(), dc.to_vec()).unwrap();
+        test_continuous(seed as u64, dist, |x| constant_cdf(x, b, dc));
+        let dist = rand_distr::PiecewiseLinear::new(b.to_vec(), dl.to_vec()).unwrap();
+        test_continuous(seed as u64, dist, |x| linear_cdf(x, b, dl));
+    }
+}
+
+#[test]
+fn empirical() {
+    use rand_distr::{Bandwidth, Empirical, Kernel};
+
+    let data = vec![-3.0, 0.0, 1.0, 1.0, 4.0, 7.0, 7.0, 7.0, 12.0];
+    let n = data.len() as f64;
+
+    // Resampling of integer observations
+    let dist = Empirical::new(data.clone()).unwrap();
+    test_discrete(0, dist.clone(), |k| {
+        data.iter().filter(|&&x| x <= k as f64).count() as f64 / n
+    });
+
+    // Linear interpolation of the ECDF (repeated observations would be atoms)
+    let distinct = [-3.0, 0.0, 1.0, 1.5, 4.0, 7.0, 12.0];
+    let interpolated = Empirical::new(distinct.to_vec()).unwrap().interpolated();
+    test_continuous(1, interpolated, |x| {
+        if x <= distinct[0] {
+            return 0.0;
+        }
+        for i in 0..distinct.len() - 1 {
+            if x < distinct[i + 1] {
+                let t = (x - distinct[i]) / (distinct[i + 1] - distinct[i]);
+                return (i as f64 + t) / (distinct.len() - 1) as f64;
+            }
+        }
+        1.0
+    });
+
+    // Kernel density estimates
+    let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+    for (seed, bandwidth) in [
+        Bandwidth::Silverman,
+        Bandwidth::Scott,
+        Bandwidth::Fixed(0.3),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let kde = dist
+            .clone()
+            .with_kernel(Kernel::Gaussian, bandwidth)
+            .unwrap();
+        let (_, h) = kde.kernel().unwrap();
+        test_continuous(2 + seed as u64, kde, |x| {
+            data.iter().map(|y| normal.cdf((x - y) / h)).sum::<f64>() / n
+        });
+
+        let kde = dist
+            .clone()
+            .with_kernel(Kernel::Epanechnikov, bandwidth)
+            .unwrap();
+        let (_, h) = kde.kernel().unwrap();
+        test_continuous(5 + seed as u64, kde, |x| {
+            data.iter()
+                .map(|y| {
+                    let s = ((x - y) / (h * 5f64.sqrt())).clamp(-1.0, 1.0);
+                    0.5 + 0.75 * (s - s.powi(3) / 3.0)
+                })
+                .sum::<f64>()
+                / n
+        });
+    }
+}
+
+#[test]
+fn mixture() {
+    use rand_distr::{DynMixture, Mixture};
+
+    let normal_cdf =
+        |x: f64, mu: f64, sigma: f64| statrs::distribution::Normal::new(mu, sigma).unwrap().cdf(x);
+
+    let components = vec![
+        rand_distr::Normal::new(-2.0, 0.5).unwrap(),
+        rand_distr::Normal::new(0.0, 1.0).unwrap(),
+        rand_distr::Normal::new(3.0, 2.0).unwrap(),
+    ];
+    let dist = Mixture::new(components, vec![0.2, 0.5, 0.3]).unwrap();
+    test_continuous(0, dist, |x| {
+        0.2 * normal_cdf(x, -2.0, 0.5)
+            + 0.5 * normal_cdf(x, 0.0, 1.0)
+            + 0.3 * normal_cdf(x, 3.0, 2.0)
+    });
+
+    let dist = DynMixture::new(
+        vec![
+            Box::new(rand_distr::Normal::new(1.0, 2.0).unwrap()),
+            Box::new(rand_distr::Exp::new(0.5).unwrap()),
+        ],
+        vec![3u32, 1],
+    )
+    .unwrap();
+    test_continuous(1, dist, |x| {
+        let exp_cdf = if x > 0.0 { 1.0 - (-0.5 * x).exp() } else { 0.0 };
+        0.75 * normal_cdf(x, 1.0, 2.0) + 0.25 * exp_cdf
+    });
+}
+
+#[test]
+fn zero_inflated_and_hurdle() {
+    use rand_distr::{Hurdle, ZeroInflated};
+
+    fn check<D>(seed: u64, pi: f64, d: D, cdf: impl Fn(i64) -> f64)
+    where
+        D: rand_distr::SampleNonZero<u64> + Copy,
+    {
+        let zero_inflated = ZeroInflated::new(pi, d).unwrap();
+        test_discrete(seed, zero_inflated, |k| {
+            if k < 0 { 0.0 } else { pi + (1.0 - pi) * cdf(k) }
+        });
+        let hurdle = Hurdle::new(pi, d).unwrap();
+        let p0 = cdf(0);
+        test_discrete(seed, hurdle, |k| {
+            if k < 0 {
+                0.0
+            } else {
+                pi + (1.0 - pi) * (cdf(k) - p0) / (1.0 - p0)
+            }
+        });
+    }
+
+    for (seed, (n, p)) in [(10, 0.3), (5, 0.01), (1000, 1e-4), (3, 0.9)]
+        .into_iter()
+        .enumerate()
+    {
+        let binomial = statrs::distribution::Binomial::new(p, n).unwrap();
+        let d = rand_distr::Binomial::new(n, p).unwrap();
+        check(seed as u64, 0.3, d, |k| binomial.cdf(k as u64));
+    }
+
+    for (seed, p) in [0.5, 0.9, 0.01].into_iter().enumerate() {
+        let d = rand_distr::Geometric::new(p).unwrap();
+        check(10 + seed as u64, 0.2, d, |k| {
+            1.0 - (1.0 - p).powi(k as i32 + 1)
+        });
+    }
+
+    for (seed, lambda) in [0.01, 0.5, 0.7, 3.0, 50.0].into_iter().enumerate() {
+        let poisson = statrs::distribution::Poisson::new(lambda).unwrap();
+        let d = rand_distr::Poisson::new(lambda).unwrap();
+        check(20 + seed as u64, 0.4, d, |k| poisson.cdf(k as u64));
+    }
+}
+
+/// CDF of the sum of `N ~ Poisson(lambda)` `Gamma(shape, scale)` variables
+fn compound_poisson_gamma_cdf(x: f64, lambda: f64, shape: f64, scale: f64) -> f64 {
+    if x < 0.0 {
+        return 0.0;
+    }
+    let poisson = statrs::distribution::Poisson::new(lambda).unwrap();
+    // Skip the negligible terms beyond 7 standard deviations of N
+    let min_n = (lambda - 7.0 * lambda.sqrt()).max(1.0) as u64;
+    let max_n = (lambda + 7.0 * lambda.sqrt() + 7.0) as u64;
+    let mut cdf = (-lambda).exp();
+    for n in min_n..=max_n {
+        let gamma = statrs::distribution::Gamma::new(n as f64 * shape, 1.0 / scale).unwrap();
+        cdf += statrs::distribution::Discrete::pmf(&poisson, n) * gamma.cdf(x);
+    }
+    cdf
+}
+
+#[test]
+fn compound_poisson() {
+    // The point mass exp(-λ) at zero is negligible for these parameters
+    for (seed, (lambda, rate)) in [(10.0, 0.1)].into_iter().enumerate() {
+        let severity = rand_distr::Exp::new(rate).unwrap();
+        let dist = rand_distr::CompoundPoisson::new(lambda, severity).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            compound_poisson_gamma_cdf(x, lambda, 1.0, 1.0 / rate)
+        });
+    }
+}
+
+#[test]
+fn tweedie() {
+    // The point mass exp(-λ) at zero is negligible for these parameters
+    let parameters = [(10.0, 0.5, 1.3), (30.0, 1.0, 1.7)];
+
+    for (seed, (mu, phi, p)) in parameters.into_iter().enumerate() {
+        let lambda: f64 = mu.powf(2.0 - p) / (phi * (2.0 - p));
+        let shape = (2.0 - p) / (p - 1.0);
+        let scale = phi * (p - 1.0) * mu.powf(p - 1.0);
+        assert!((-lambda).exp() < 1e-4);
+        let dist = rand_distr::Tweedie::new(mu, phi, p).unwrap();
+        test_continuous(seed as u64, dist, |x| {
+            compound_poisson_gamma_cdf(x, lambda, shape, scale)
+        });
+    }
+}
+
 #[test]
 fn triangular() {
     fn cdf(x: f64, a: f64, b: f64, c: f64) -> f64 {
@@ -369,6 +939,42 @@ fn triangular() {
     }
 }
 
+#[test]
+fn trapezoidal() {
+    fn cdf(x: f64, a: f64, d: f64, b: f64, c: f64, m: f64, n: f64) -> f64 {
+        let total = (b - a) / m + (c - b) + (d - c) / n;
+        if x <= a {
+            0.0
+        } else if x < b {
+            (b - a) / m * ((x - a) / (b - a)).powf(m) / total
+        } else if x < c {
+            ((b - a) / m + (x - b)) / total
+        } else if x < d {
+            1.0 - (d - c) / n * ((d - x) / (d - c)).powf(n) / total
+        } else {
+            1.0
+        }
+    }
+
+    let parameters = [
+        (0.0, 1.0, 0.25, 0.75, 2.0, 2.0),
+        (0.0, 1.0, 0.0, 0.5, 2.0, 2.0),
+        (-100.0, 100.0, 0.0, 0.0, 2.0, 2.0),
+        (0.0, 1.0, 0.25, 0.75, 1.0, 1.0),
+        (0.0, 10.0, 2.0, 5.0, 3.0, 0.5),
+        (-1.0, 2.0, 0.5, 1.0, 0.3, 7.0),
+    ];
+
+    for (seed, (a, d, b, c, m, n)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::Trapezoidal::generalized(a, d, b, c, m, n).unwrap();
+        test_continuous(seed as u64, dist, |x| cdf(x, a, d, b, c, m, n));
+        if m == 2.0 && n == 2.0 {
+            let dist = rand_distr::Trapezoidal::new(a, d, b, c).unwrap();
+            test_continuous(seed as u64, dist, |x| cdf(x, a, d, b, c, m, n));
+        }
+    }
+}
+
 fn binomial_cdf(k: i64, p: f64, n: u64) -> f64 {
     if k < 0 {
         return 0.0;
@@ -406,6 +1012,125 @@ fn binomial() {
     }
 }
 
+#[test]
+fn skellam() {
+    use statrs::distribution::Discrete;
+
+    for (seed, (mu1, mu2)) in [(1.0, 1.0), (0.3, 4.0), (25.0, 12.0)]
+        .into_iter()
+        .enumerate()
+    {
+        let dist = rand_distr::Skellam::new(mu1, mu2).unwrap();
+        let poisson_1 = statrs::distribution::Poisson::new(mu1).unwrap();
+        let poisson_2 = statrs::distribution::Poisson::new(mu2).unwrap();
+        let max_n2 = (mu2 + 10.0 * mu2.sqrt() + 20.0) as u64;
+        test_discrete(seed as u64, dist, |k| {
+            // P(N1 - N2 <= k) = sum over n2 of P(N2 = n2) P(N1 <= k + n2)
+            (0..=max_n2)
+                .filter(|&n2| k + n2 as i64 >= 0)
+                .map(|n2| poisson_2.pmf(n2) * poisson_1.cdf((k + n2 as i64) as u64))
+                .sum()
+        });
+    }
+}
+
+#[test]
+fn discrete_laplace() {
+    for (seed, p) in [0.0, 0.1, 0.5, 0.95].into_iter().enumerate() {
+        let dist = rand_distr::DiscreteLaplace::new(p).unwrap();
+        test_discrete(seed as u64, dist, |k| {
+            if k >= 0 {
+                1.0 - p.powi(k as i32 + 1) / (1.0 + p)
+            } else {
+                p.powi(-k as i32) / (1.0 + p)
+            }
+        });
+    }
+}
+
+#[test]
+fn poisson_binomial() {
+    // The refined normal approximation used for many trials with a large
+    // variance is not exact, so only the exact methods are tested here.
+    let parameters: [Vec<f64>; 3] = [
+        vec![0.1, 0.5, 0.9, 1.0, 0.0],
+        (0..16).map(|i| (i as f64 + 0.5) / 16.0).collect(),
+        (0..300).map(|i| ((i * 37) % 100) as f64 / 100.0).collect(),
+    ];
+
+    for (seed, probabilities) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::PoissonBinomial::new(&probabilities).unwrap();
+        let pmf = dist.pmf();
+        test_discrete(seed as u64, dist, |k| {
+            if k < 0 {
+                0.0
+            } else {
+                pmf.iter().take(k as usize + 1).sum()
+            }
+        });
+    }
+
+    // Many trials with a small variance, where equal probabilities reduce to
+    // a shifted binomial distribution
+    let parameters = [
+        (0, 0, 2000, 0.999),
+        (100, 1200, 700, 0.99),
+        (0, 0, 2000, 1e-3),
+    ];
+
+    for (seed, (zeros, ones, n, p)) in parameters.into_iter().enumerate() {
+        let mut probabilities = vec![0.0; zeros];
+        probabilities.extend(core::iter::repeat_n(1.0, ones));
+        probabilities.extend(core::iter::repeat_n(p, n as usize));
+        let dist = rand_distr::PoissonBinomial::new(&probabilities).unwrap();
+        let binomial = statrs::distribution::Binomial::new(p, n).unwrap();
+        test_discrete(3 + seed as u64, dist, |k| {
+            if k < ones as i64 {
+                0.0
+            } else {
+                binomial.cdf((k - ones as i64) as u64)
+            }
+        });
+    }
+}
+
+#[test]
+fn conway_maxwell_poisson() {
+    let parameters = [(1.5, 1.0), (2.0, 0.5), (0.5, 0.3), (10.0, 2.0), (50.0, 3.5)];
+
+    for (seed, (lambda, nu)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::ConwayMaxwellPoisson::new(lambda, nu).unwrap();
+        let ratio = |k: f64| lambda / (k + 1.0).powf(nu);
+        // normalizing constant, truncated where the terms are negligible
+        let z: f64 = (0..1000)
+            .scan(1.0, |term, k| {
+                let t = *term;
+                *term *= ratio(k as f64);
+                Some(t)
+            })
+            .sum();
+        test_discrete(seed as u64, dist, |k| recurrence_cdf(k, 1.0 / z, ratio));
+    }
+}
+
+#[test]
+fn generalized_poisson() {
+    let parameters = [(1.0, 0.0), (2.0, 0.3), (0.5, 0.8), (10.0, 0.5)];
+
+    for (seed, (theta, lambda)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::GeneralizedPoisson::new(theta, lambda).unwrap();
+        let ln_pmf = |k: f64| {
+            theta.ln() + (k - 1.0) * (theta + lambda * k).ln()
+                - theta
+                - lambda * k
+                - Gamma::ln_gamma(k + 1.0).0
+        };
+        test_discrete(seed as u64, dist, |k| {
+            recurrence_cdf(k, (-theta).exp(), |i| (ln_pmf(i + 1.0) - ln_pmf(i)).exp())
+        });
+    }
+}
+
 #[test]
 fn geometric() {
     fn cdf(k: i64, p: f64) -> f64 {
@@ -424,6 +1149,189 @@ fn geometric() {
     }
 }
 
+#[test]
+fn negative_binomial() {
+    let parameters = [
+        (1.0, 0.3),
+        (2.5, 0.4),
+        (0.3, 0.05),
+        (20.0, 0.9),
+        (100.0, 0.2),
+    ];
+
+    for (seed, (r, p)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::NegativeBinomial::new(r, p).unwrap();
+        let nb = statrs::distribution::NegativeBinomial::new(r, p).unwrap();
+        test_discrete(seed as u64, dist, |k| {
+            if k < 0 { 0.0 } else { nb.cdf(k as u64) }
+        });
+    }
+}
+
+/// CDF of a discrete distribution on the non-negative integers, by summing
+/// the density from `p0` using the ratio of successive terms
+fn recurrence_cdf(k: i64, p0: f64, ratio: impl Fn(f64) -> f64) -> f64 {
+    let mut pk = p0;
+    let mut sum = 0.0;
+    for i in 0..=k {
+        sum += pk;
+        pk *= ratio(i as f64);
+    }
+    sum.min(1.0)
+}
+
+fn beta_binomial_cdf(k: i64, n: u64, a: f64, b: f64) -> f64 {
+    if k >= n as i64 {
+        return 1.0;
+    }
+    let nf = n as f64;
+    let p0 = (a.ln_beta(nf + b) - a.ln_beta(b)).exp();
+    recurrence_cdf(k, p0, |i| {
+        (nf - i) * (i + a) / ((i + 1.0) * (nf - i - 1.0 + b))
+    })
+}
+
+#[test]
+fn beta_binomial() {
+    let parameters = [
+        (10, 2.0, 3.0),
+        (30, 8.0, 1.5),
+        (200, 0.5, 0.5),
+        (500, 4.0, 6.0),
+    ];
+    for (seed, (n, a, b)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::BetaBinomial::new(n, a, b).unwrap();
+        test_discrete(seed as u64, dist, |k| beta_binomial_cdf(k, n, a, b));
+    }
+
+    // For an integer a, P(X = 0) = Π (b + j) / (n + b + j) over j < a, which
+    // avoids the cancelling log-beta values of a large n.
+    for (seed, n) in [1e12, 1e14].into_iter().enumerate() {
+        let (a, b) = (2.0, n / 5.0);
+        let dist = rand_distr::BetaBinomial::new(n as u64, a, b).unwrap();
+        let p0 = b * (b + 1.0) / ((n + b) * (n + b + 1.0));
+        test_discrete(20 + seed as u64, dist, |k| {
+            recurrence_cdf(k, p0, |i| {
+                (n - i) * (i + a) / ((i + 1.0) * (n - i - 1.0 + b))
+            })
+        });
+    }
+
+    let parameters = [(3.0, 5.0, 2.0), (0.7, 2.5, 4.0), (10.0, 30.0, 20.0)];
+    for (seed, (r, a, b)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::BetaNegativeBinomial::new(r, a, b).unwrap();
+        let p0 = ((a + r).ln_beta(b) - a.ln_beta(b)).exp();
+        test_discrete(10 + seed as u64, dist, |k| {
+            recurrence_cdf(k, p0, |i| (r + i) * (b + i) / ((i + 1.0) * (a + r + b + i)))
+        });
+    }
+}
+
+#[test]
+fn logarithmic_series() {
+    for (seed, p) in [0.1, 0.5, 0.9, 0.999].into_iter().enumerate() {
+        let dist = rand_distr::LogarithmicSeries::new(p).unwrap();
+        let p1 = -p / (-p).ln_1p();
+        test_discrete(seed as u64, dist, |k| {
+            recurrence_cdf(k - 1, p1, |i| p * (i + 1.0) / (i + 2.0))
+        });
+    }
+}
+
+#[test]
+fn yule_simon() {
+    for (seed, rho) in [0.5, 1.0, 2.5, 10.0].into_iter().enumerate() {
+        let dist = rand_distr::YuleSimon::new(rho).unwrap();
+        test_discrete(seed as u64, dist, |k| {
+            if k < 1 {
+                0.0
+            } else {
+                let k = k as f64;
+                1.0 - k * k.ln_beta(rho + 1.0).exp()
+            }
+        });
+    }
+}
+
+#[test]
+fn dirichlet_multinomial() {
+    use rand_distr::Distribution;
+    use rand_distr::multi::DirichletMultinomial;
+
+    // The marginal distributions are beta-binomial
+    let alpha = [0.3, 2.0, 5.0];
+    let alpha_sum = alpha.iter().sum::<f64>();
+    for (seed, (n, i)) in [(10, 0), (10, 2), (500, 0), (500, 2)]
+        .into_iter()
+        .enumerate()
+    {
+        let dist = DirichletMultinomial::new(n, alpha).unwrap();
+        let marginal = dist.map(move |x: [u64; 3]| x[i]);
+        let (a, b) = (alpha[i], alpha_sum - alpha[i]);
+        test_discrete(seed as u64, marginal, |k| beta_binomial_cdf(k, n, a, b));
+    }
+
+    // A small first category of a large `n`; see `beta_binomial` for the
+    // reference probability of zero.
+    for (seed, n) in [1e13, 1e14].into_iter().enumerate() {
+        let alpha = [2.0, n / 10.0, n / 10.0];
+        let dist = DirichletMultinomial::new(n as u64, alpha).unwrap();
+        let marginal = dist.map(|x: [u64; 3]| x[0]);
+        let (a, b) = (2.0, n / 5.0);
+        let p0 = b * (b + 1.0) / ((n + b) * (n + b + 1.0));
+        test_discrete(10 + seed as u64, marginal, |k| {
+            recurrence_cdf(k, p0, |i| {
+                (n - i) * (i + a) / ((i + 1.0) * (n - i - 1.0 + b))
+            })
+        });
+    }
+}
+
+#[test]
+fn multinomial() {
+    use rand_distr::Distribution;
+    use rand_distr::multi::Multinomial;
+
+    // The marginal distributions are binomial
+    let p = [0.1, 0.25, 0.0, 0.65];
+    for (seed, n) in [20, 1000].into_iter().enumerate() {
+        let dist = Multinomial::new(n, p).unwrap();
+        for (i, &pi) in p.iter().enumerate() {
+            if pi == 0.0 {
+                continue;
+            }
+            let marginal = dist.map(move |x: [u64; 4]| x[i]);
+            test_discrete(10 * seed as u64 + i as u64, marginal, |k| {
+                binomial_cdf(k, pi, n)
+            });
+        }
+    }
+}
+
+#[test]
+fn multivariate_hypergeometric() {
+    use rand_distr::Distribution;
+    use rand_distr::multi::MultivariateHypergeometric;
+
+    // The marginal distributions are hypergeometric. Each sample constructs
+    // several `Hypergeometric` distributions, so only some are checked.
+    let counts = [10, 0, 200, 35, 1000];
+    let total = counts.iter().sum::<u64>();
+    for (seed, (n, i)) in [(20, 2), (600, 0), (600, 4)].into_iter().enumerate() {
+        let dist = MultivariateHypergeometric::new(counts, n).unwrap();
+        let hypergeometric =
+            statrs::distribution::Hypergeometric::new(total, counts[i], n).unwrap();
+        let marginal = dist.map(move |x: [u64; 5]| x[i]);
+        test_discrete(seed as u64, marginal, |k| {
+            if k < 0 {
+                0.0
+            } else {
+                hypergeometric.cdf(k as u64)
+            }
+        });
+    }
+}
+
 #[test]
 fn hypergeometric() {
     fn cdf(x: i64, n: u64, k: u64, n_: u64) -> f64 {
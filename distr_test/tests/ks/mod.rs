@@ -117,6 +117,25 @@ pub fn test_continuous(seed: u64, dist: impl Distribution<f64>, cdf: impl Fn(f64
     assert!(ks_statistic < critical_value);
 }
 
+/// Tests a distribution against an approximation of its CDF, which differs
+/// from the exact CDF by at most `max_error`.
+/// The CDF has to be continuous.
+pub fn test_continuous_approx(
+    seed: u64,
+    dist: impl Distribution<f64>,
+    cdf: impl Fn(f64) -> f64,
+    max_error: f64,
+) {
+    let ecdf = sample_ecdf(seed, dist);
+    let ks_statistic = kolmogorov_smirnov_statistic_continuous(ecdf, cdf);
+
+    let critical_value = critical_value() + max_error;
+
+    println!("KS statistic: {ks_statistic}");
+    println!("Critical value: {critical_value}");
+    assert!(ks_statistic < critical_value);
+}
+
 /// Tests a distribution over integers against an analytical CDF.
 /// The analytical CDF must not have jump points which are not integers.
 pub fn test_discrete<I, D, F>(seed: u64, dist: D, cdf: F)
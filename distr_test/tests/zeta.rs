@@ -24,7 +24,7 @@ fn zeta() {
 
     for (seed, s) in parameters.into_iter().enumerate() {
         let dist = rand_distr::Zeta::new(s).unwrap();
-        test_discrete(seed as u64, dist, |k| cdf(k, s));
+        test_discrete::<u64, _, _>(seed as u64, dist, |k| cdf(k, s));
     }
 }
 
@@ -44,10 +44,41 @@ fn zipf() {
 
     for (seed, (n, x)) in parameters.into_iter().enumerate() {
         let dist = rand_distr::Zipf::new(n as f64, x).unwrap();
-        test_discrete(seed as u64, dist, |k| cdf(k, n, x));
+        test_discrete::<u64, _, _>(seed as u64, dist, |k| cdf(k, n, x));
     }
 }
 
+#[test]
+fn zipf_mandelbrot() {
+    fn cdf(k: i64, n: u64, s: f64, q: f64) -> f64 {
+        if k < 1 {
+            return 0.0;
+        }
+        if k > n as i64 {
+            return 1.0;
+        }
+        shifted_harmonic(k as u64, s, q) / shifted_harmonic(n, s, q)
+    }
+
+    let parameters = [
+        (1000, 1.0, 0.0),
+        (1000, 1.1, 2.7),
+        (500, 2.0, 10.0),
+        (100, 0.5, -0.5),
+        (20, 3.0, 1000.0),
+        (50, 2.0, 1e12),
+    ];
+
+    for (seed, (n, s, q)) in parameters.into_iter().enumerate() {
+        let dist = rand_distr::ZipfMandelbrot::new(n, s, q).unwrap();
+        test_discrete(seed as u64, dist, |k| cdf(k, n, s, q));
+    }
+}
+
+fn shifted_harmonic(n: u64, m: f64, q: f64) -> f64 {
+    (1..=n).fold(0.0, |acc, x| acc + (x as f64 + q).powf(-m))
+}
+
 fn gen_harmonic(n: u64, m: f64) -> f64 {
     match n {
         0 => 1.0,
@@ -360,7 +360,7 @@ fn sample_one(rng: &mut TestRng, params: &Parameters) -> Option<()> {
             assert!((min..=max).contains(&v), "{}", v);
         }
         P::Poisson { lambda } => {
-            let v = black_box(Poisson::new(lambda).ok()?.sample(rng));
+            let v: Float = black_box(Poisson::new(lambda).ok()?.sample(rng));
             assert!(
                 v >= 0.0 && v <= 2.0_f32.powi(64) && v.fract() == 0.0,
                 "{}",
@@ -440,11 +440,11 @@ fn sample_one(rng: &mut TestRng, params: &Parameters) -> Option<()> {
             assert!(v >= 0.0, "{}", v);
         }
         P::Zeta { s } => {
-            let v = black_box(Zeta::new(s).ok()?.sample(rng));
+            let v: Float = black_box(Zeta::new(s).ok()?.sample(rng));
             assert!(v >= 0.0 && (v.is_infinite() || v.fract() == 0.0), "{}", v);
         }
         P::Zipf { n, s } => {
-            let v = black_box(Zipf::new(n, s).ok()?.sample(rng));
+            let v: Float = black_box(Zipf::new(n, s).ok()?.sample(rng));
             assert!((v.is_infinite() || v.fract() == 0.0), "{}", v);
             assert!(v >= 0.0 && v <= n.floor(), "{}", v);
         }
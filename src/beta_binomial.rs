@@ -0,0 +1,505 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The beta-binomial and beta-negative-binomial distributions.
+
+use crate::binomial::sample_binomial;
+use crate::utils::ln_gamma;
+use crate::{Beta, Distribution, Gamma, Poisson};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [beta-binomial distribution](https://en.wikipedia.org/wiki/Beta-binomial_distribution)
+/// `BetaBinomial(n, α, β)`.
+///
+/// This is the distribution of the number of successes in `n` Bernoulli
+/// trials, where the probability of success is itself `Beta(α, β)`
+/// distributed (and shared by all trials). It has the same mean as
+/// `Binomial(n, α / (α + β))`, but a larger variance.
+///
+/// # Density function
+///
+/// `f(k) = C(n, k) B(k + α, n - k + β) / B(α, β)` for `0 <= k <= n`,
+/// where `B` is the beta function.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{BetaBinomial, Distribution};
+///
+/// let bb = BetaBinomial::new(20, 2.0, 3.0).unwrap();
+/// let v = bb.sample(&mut rand::rng());
+/// println!("{} is from a beta-binomial distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// When the expected number of successes (or of failures, if that is
+/// smaller) is small, samples are generated by inversion, searching the
+/// cumulative distribution function from zero using the recurrence of the
+/// density function. Otherwise a success probability is sampled from
+/// [`Beta`] and the count from [`Binomial`](crate::Binomial). The setup of
+/// the binomial sampler depends on the success probability, so it is
+/// repeated for every sample; only the validation of the parameters is
+/// skipped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Inversion(Inversion, bool),
+    BetaBinomial(Beta<f64>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Inversion {
+    n: u64,
+    a: f64,
+    b: f64,
+    // probability of zero successes
+    p0: f64,
+}
+
+/// Error type returned from [`BetaBinomial::new`] and
+/// [`BetaNegativeBinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `alpha <= 0`, `alpha = ∞` or `nan`.
+    AlphaNotPositive,
+    /// `beta <= 0`, `beta = ∞` or `nan`.
+    BetaNotPositive,
+    /// `r <= 0`, `r = ∞` or `nan`.
+    RNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::AlphaNotPositive => {
+                "alpha is not positive and finite in beta-binomial distribution"
+            }
+            Error::BetaNotPositive => {
+                "beta is not positive and finite in beta-binomial distribution"
+            }
+            Error::RNotPositive => {
+                "r is not positive and finite in beta-negative-binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+fn check_shape(alpha: f64, beta: f64) -> Result<(), Error> {
+    if !(alpha > 0.0 && alpha.is_finite()) {
+        return Err(Error::AlphaNotPositive);
+    }
+    if !(beta > 0.0 && beta.is_finite()) {
+        return Err(Error::BetaNotPositive);
+    }
+    Ok(())
+}
+
+impl BetaBinomial {
+    /// Construct a new `BetaBinomial` distribution with the number of trials
+    /// `n` and the shape parameters `alpha` and `beta` of the probability of
+    /// success.
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Result<BetaBinomial, Error> {
+        check_shape(alpha, beta)?;
+
+        // The distribution is symmetrical with respect to swapping alpha and
+        // beta and reflecting k -> n - k.
+        let flipped = alpha > beta;
+        let (a, b) = if flipped {
+            (beta, alpha)
+        } else {
+            (alpha, beta)
+        };
+
+        // The expected number of steps of the inversion is mean + 1. The
+        // alternative pays for a `Beta` sample in addition to a `Binomial`
+        // sample, hence the threshold is higher than the one of `Binomial`.
+        const INVERSION_THRESHOLD: f64 = 20.0;
+
+        let mean = n as f64 * a / (a + b);
+        let method = if mean < INVERSION_THRESHOLD {
+            let p0 = ln_probability_of_zero(n, a, b).exp();
+            Method::Inversion(Inversion { n, a, b, p0 }, flipped)
+        } else {
+            Method::BetaBinomial(Beta::new(alpha, beta).unwrap())
+        };
+
+        Ok(BetaBinomial {
+            n,
+            alpha,
+            beta,
+            method,
+        })
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the shape parameter `alpha`.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the shape parameter `beta`.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+/// `ln P(X = 0) = ln(Γ(n + b) Γ(a + b) / (Γ(b) Γ(n + a + b)))` for `a <= b`
+/// and a mean `n a / (a + b)` below the inversion threshold.
+///
+/// The difference of the four log-gamma values cancels catastrophically for
+/// large `n`, so the terms of Stirling's series are grouped such that only
+/// small quantities are subtracted.
+fn ln_probability_of_zero(n: u64, a: f64, b: f64) -> f64 {
+    // Exact product of (b + i) / (a + b + i) for a small number of trials.
+    // Beyond it, a / b < 0.1 follows from the bound on the mean.
+    const PRODUCT_LIMIT: u64 = 256;
+    // Smallest argument of Stirling's series
+    const STIRLING_MIN: f64 = 10.0;
+
+    if n <= PRODUCT_LIMIT {
+        return (0..n).map(|i| (-a / (a + b + i as f64)).ln_1p()).sum();
+    }
+
+    let n_f = n as f64;
+    if b >= STIRLING_MIN {
+        // ln Γ(x + a) - ln Γ(x) = a ln(x) + ln_gamma_shift(x, a)
+        -a * (n_f / b).ln_1p() + ln_gamma_shift(b, a) - ln_gamma_shift(n_f + b, a)
+    } else {
+        // a <= b < 10, so there is no cancellation in the first difference.
+        let ln_ratio_b = ln_gamma(b + a) - ln_gamma(b);
+        let x = n_f + b;
+        let ln_ratio_x = if x >= STIRLING_MIN {
+            a * x.ln() + ln_gamma_shift(x, a)
+        } else {
+            ln_gamma(x + a) - ln_gamma(x)
+        };
+        ln_ratio_b - ln_ratio_x
+    }
+}
+
+/// `ln Γ(x + a) - ln Γ(x) - a ln(x)` for `x >= 10`, using Stirling's series.
+fn ln_gamma_shift(x: f64, a: f64) -> f64 {
+    // Remainder of Stirling's series
+    fn omega(x: f64) -> f64 {
+        let r = 1.0 / (x * x);
+        (1.0 / 12.0 - r * (1.0 / 360.0 - r * (1.0 / 1260.0 - r / 1680.0))) / x
+    }
+
+    let t = a / x;
+    // x ln(1 + t) - a, expanded in t to avoid the cancellation
+    let main = if t < 0.1 {
+        let mut sum = 0.0;
+        let mut power = -t;
+        let mut k = 2.0;
+        while power.abs() > f64::EPSILON * sum.abs() {
+            sum += power / k;
+            power *= -t;
+            k += 1.0;
+        }
+        a * sum
+    } else {
+        x * t.ln_1p() - a
+    };
+    main + (a - 0.5) * t.ln_1p() + (omega(x + a) - omega(x))
+}
+
+impl Distribution<u64> for Inversion {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let Inversion { n, a, b, p0 } = *self;
+        'outer: loop {
+            let mut u: f64 = rng.random();
+            let mut k = 0;
+            let mut pk = p0;
+            while u > pk && k < n {
+                u -= pk;
+                let k_f = k as f64;
+                let n_k = (n - k) as f64;
+                pk *= n_k * (k_f + a) / ((k_f + 1.0) * (n_k - 1.0 + b));
+                k += 1;
+                // The probabilities underflowed before their rounded sum
+                // reached `u`, the remaining mass is negligible.
+                if pk == 0.0 {
+                    continue 'outer;
+                }
+            }
+            return k;
+        }
+    }
+}
+
+impl Distribution<u64> for BetaBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match &self.method {
+            Method::Inversion(inversion, flipped) => {
+                let k = inversion.sample(rng);
+                if *flipped { self.n - k } else { k }
+            }
+            Method::BetaBinomial(beta) => {
+                let p: f64 = beta.sample(rng);
+                sample_binomial(self.n, p, rng)
+            }
+        }
+    }
+}
+
+/// The [beta-negative-binomial distribution](https://en.wikipedia.org/wiki/Beta_negative_binomial_distribution)
+/// `BetaNegativeBinomial(r, α, β)`.
+///
+/// This is the distribution of the number of failures before the `r`-th
+/// success in a series of Bernoulli trials, where the probability of
+/// success is itself `Beta(α, β)` distributed. That is, it is the
+/// [`NegativeBinomial`](crate::NegativeBinomial) distribution with a
+/// beta-distributed `p`. It is heavy-tailed: the mean is only finite for
+/// `α > 1` and the variance only for `α > 2`.
+///
+/// # Density function
+///
+/// `f(k) = Γ(r + k) / (k! Γ(r)) B(α + r, β + k) / B(α, β)` for `k >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{BetaNegativeBinomial, Distribution};
+///
+/// let bnb = BetaNegativeBinomial::new(3.0, 5.0, 2.0).unwrap();
+/// let v = bnb.sample(&mut rand::rng());
+/// println!("{} is from a beta-negative-binomial distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// The odds of failure `(1 - p) / p` of a `Beta(α, β)` distributed `p`
+/// follow the beta prime distribution, which is the ratio of independent
+/// `Gamma(β, 1)` and `Gamma(α, 1)` variables. Samples are thus generated
+/// from the [`Poisson`] distribution whose rate is the product of
+/// `Gamma(r, 1)` and these odds, without a per-sample distribution setup
+/// other than that of `Poisson`.
+///
+/// When the sampled rate exceeds [`Poisson::MAX_LAMBDA`], the sample
+/// saturates to `u64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetaNegativeBinomial {
+    r: f64,
+    alpha: f64,
+    beta: f64,
+    gamma_r: Gamma<f64>,
+    gamma_alpha: Gamma<f64>,
+    gamma_beta: Gamma<f64>,
+}
+
+impl BetaNegativeBinomial {
+    /// Construct a new `BetaNegativeBinomial` distribution with the number
+    /// of successes `r` and the shape parameters `alpha` and `beta` of the
+    /// probability of success.
+    ///
+    /// `r` need not be an integer.
+    pub fn new(r: f64, alpha: f64, beta: f64) -> Result<BetaNegativeBinomial, Error> {
+        if !(r > 0.0 && r.is_finite()) {
+            return Err(Error::RNotPositive);
+        }
+        check_shape(alpha, beta)?;
+
+        Ok(BetaNegativeBinomial {
+            r,
+            alpha,
+            beta,
+            gamma_r: Gamma::new(r, 1.0).unwrap(),
+            gamma_alpha: Gamma::new(alpha, 1.0).unwrap(),
+            gamma_beta: Gamma::new(beta, 1.0).unwrap(),
+        })
+    }
+
+    /// Returns the number of successes `r`.
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    /// Returns the shape parameter `alpha`.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the shape parameter `beta`.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl Distribution<u64> for BetaNegativeBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let x = self.gamma_r.sample(rng);
+        let odds = self.gamma_beta.sample(rng) / self.gamma_alpha.sample(rng);
+        let lambda = x * odds;
+        if !(lambda > 0.0) {
+            // possible for very small r or beta
+            return 0;
+        }
+        match Poisson::new(lambda) {
+            Ok(poisson) => {
+                let k: f64 = poisson.sample(rng);
+                k as u64
+            }
+            Err(_) => u64::MAX,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_mean_and_variance<D: Distribution<u64>>(
+        d: D,
+        expected_mean: f64,
+        expected_variance: f64,
+        seed: u64,
+    ) {
+        let mut rng = crate::test::rng(seed);
+        let mut results = [0.0; 10000];
+        for i in results.iter_mut() {
+            *i = d.sample(&mut rng) as f64;
+        }
+
+        let mean = results.iter().sum::<f64>() / results.len() as f64;
+        assert!((mean - expected_mean).abs() < expected_mean / 20.0);
+
+        let variance =
+            results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+        assert!((variance - expected_variance).abs() < expected_variance / 5.0);
+    }
+
+    #[test]
+    fn test_beta_binomial_invalid_parameters() {
+        assert_eq!(
+            BetaBinomial::new(10, 0.0, 1.0),
+            Err(Error::AlphaNotPositive)
+        );
+        assert_eq!(
+            BetaBinomial::new(10, 1.0, f64::NAN),
+            Err(Error::BetaNotPositive)
+        );
+        assert_eq!(
+            BetaBinomial::new(10, f64::INFINITY, 1.0),
+            Err(Error::AlphaNotPositive)
+        );
+        assert!(BetaBinomial::new(0, 1.0, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_beta_binomial_moments() {
+        // covers inversion, flipped inversion and the Beta-Binomial mixture
+        for (seed, (n, a, b)) in [(10, 2.0, 3.0), (30, 8.0, 1.5), (500, 4.0, 6.0)]
+            .into_iter()
+            .enumerate()
+        {
+            let d = BetaBinomial::new(n, a, b).unwrap();
+            let n = n as f64;
+            let mean = n * a / (a + b);
+            let variance = n * a * b * (a + b + n) / ((a + b).powi(2) * (a + b + 1.0));
+            test_mean_and_variance(d, mean, variance, seed as u64);
+        }
+    }
+
+    #[test]
+    fn test_beta_binomial_probability_of_zero() {
+        // against the product of (b + i) / (a + b + i), which is exact but slow
+        for (n, a, b) in [
+            (300, 0.5, 30.0),
+            (1000, 0.01, 0.5),
+            (20_000, 0.9, 1000.0),
+            (100_000, 0.001, 8.0),
+        ] {
+            let expected: f64 = (0..n).map(|i| (-a / (a + b + i as f64)).ln_1p()).sum();
+            let ln_p0 = ln_probability_of_zero(n, a, b);
+            assert_almost_eq!(ln_p0, expected, 1e-12 * expected.abs().max(1.0));
+        }
+
+        // (b / (n + b))^a is the limit for large b
+        for n in [1e12, 1e13, 1e14, 1e18] {
+            let p0 = ln_probability_of_zero(n as u64, 2.0, n / 5.0).exp();
+            assert_almost_eq!(p0, 1.0 / 36.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_beta_binomial_large_n() {
+        for (seed, n) in [1e13, 1e14].into_iter().enumerate() {
+            let d = BetaBinomial::new(n as u64, 2.0, n / 5.0).unwrap();
+            let (a, b) = (2.0, n / 5.0);
+            let mean = n * a / (a + b);
+            let variance = n * a * b * (a + b + n) / ((a + b).powi(2) * (a + b + 1.0));
+            test_mean_and_variance(d, mean, variance, 50 + seed as u64);
+        }
+    }
+
+    #[test]
+    fn test_beta_binomial_zero_trials() {
+        let d = BetaBinomial::new(0, 2.0, 1.0).unwrap();
+        let mut rng = crate::test::rng(4);
+        assert_eq!(d.sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn test_beta_negative_binomial_invalid_parameters() {
+        assert_eq!(
+            BetaNegativeBinomial::new(0.0, 1.0, 1.0),
+            Err(Error::RNotPositive)
+        );
+        assert_eq!(
+            BetaNegativeBinomial::new(1.0, -1.0, 1.0),
+            Err(Error::AlphaNotPositive)
+        );
+        assert_eq!(
+            BetaNegativeBinomial::new(1.0, 1.0, 0.0),
+            Err(Error::BetaNotPositive)
+        );
+    }
+
+    #[test]
+    fn test_beta_negative_binomial_moments() {
+        let (r, a, b) = (3.0, 12.0, 4.0);
+        let d = BetaNegativeBinomial::new(r, a, b).unwrap();
+        let mean = r * b / (a - 1.0);
+        let variance = r * b * (r + a - 1.0) * (b + a - 1.0) / ((a - 2.0) * (a - 1.0) * (a - 1.0));
+        test_mean_and_variance(d, mean, variance, 40);
+    }
+
+    #[test]
+    fn beta_binomial_distributions_can_be_compared() {
+        assert_eq!(
+            BetaBinomial::new(10, 1.0, 2.0),
+            BetaBinomial::new(10, 1.0, 2.0)
+        );
+        assert_eq!(
+            BetaNegativeBinomial::new(2.0, 1.0, 2.0),
+            BetaNegativeBinomial::new(2.0, 1.0, 2.0)
+        );
+    }
+}
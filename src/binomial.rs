@@ -9,10 +9,9 @@
 
 //! The binomial distribution `Binomial(n, p)`.
 
-use crate::{Distribution, Uniform};
+use crate::{Distribution, StandardUniform, Uniform, uniform::SampleUniform};
 use core::cmp::Ordering;
 use core::fmt;
-#[allow(unused_imports)]
 use num_traits::Float;
 use rand::{Rng, RngExt};
 
@@ -45,41 +44,54 @@ use rand::{Rng, RngExt};
 /// ```
 ///
 /// # Numerics
-/// The implementation uses `f64` internally, which leads to rounding errors for big numbers.
-/// For very large samples (`> 2^53`) the least significant bits of the output will not be random.
+/// The implementation uses the float type `F` internally, which defaults to
+/// `f64` and leads to rounding errors for big numbers.
+/// For very large samples (`> 2^53` for `f64`) the least significant bits of the output will not be random.
 /// This means that something like `bin.sample(&mut rand::rng()) % 4` will not follow the correct distribution.
 /// The more significant bits should be correctly distributed.
+///
+/// Using `F = f32`, e.g. `Binomial::new(20, 0.3f32)`, avoids `f64`
+/// arithmetic on targets without hardware support for it, at the cost of
+/// accuracy for large `n`. [`Binomial::new`] returns [`Error::NTooLarge`]
+/// when the sampling constants cannot be represented in `F`, which happens
+/// for `f32` from `n * min(p, 1 - p)` of about `2^50`.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Binomial {
-    method: Method,
+pub struct Binomial<F = f64>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
+    n: u64,
+    p: F,
+    method: Method<F>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-enum Method {
-    Binv(Binv, bool),
-    Btpe(Btpe, bool),
-    Poisson(crate::poisson::KnuthMethod<f64>),
+enum Method<F> {
+    Binv(Binv<F>, bool),
+    Btpe(Btpe<F>, bool),
+    Poisson(crate::poisson::KnuthMethod<F>),
     Constant(u64),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-struct Binv {
-    r: f64,
-    s: f64,
-    a: f64,
+struct Binv<F> {
+    r: F,
+    s: F,
+    a: F,
     n: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-struct Btpe {
+struct Btpe<F> {
     n: u64,
-    p: f64,
+    p: F,
     m: u64,
-    p1: f64,
+    p1: F,
 }
 
 /// Error type returned from [`Binomial::new`].
@@ -91,6 +103,8 @@ pub enum Error {
     ProbabilityTooSmall,
     /// `p > 1`.
     ProbabilityTooLarge,
+    /// `n` is too large for the float type `F`.
+    NTooLarge,
 }
 
 impl fmt::Display for Error {
@@ -98,6 +112,7 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::ProbabilityTooSmall => "p < 0 or is NaN in binomial distribution",
             Error::ProbabilityTooLarge => "p > 1 in binomial distribution",
+            Error::NTooLarge => "n is too large for the float type in binomial distribution",
         })
     }
 }
@@ -105,32 +120,59 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
-impl Binomial {
+impl<F> Binomial<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
     /// Construct a new `Binomial` with the given shape parameters `n` (number
     /// of trials) and `p` (probability of success).
-    pub fn new(n: u64, p: f64) -> Result<Binomial, Error> {
-        if !(p >= 0.0) {
+    pub fn new(n: u64, p: F) -> Result<Binomial<F>, Error> {
+        if !(p >= F::zero()) {
             return Err(Error::ProbabilityTooSmall);
         }
-        if !(p <= 1.0) {
+        if !(p <= F::one()) {
             return Err(Error::ProbabilityTooLarge);
         }
 
-        if p == 0.0 {
-            return Ok(Binomial {
-                method: Method::Constant(0),
-            });
+        let method = Method::new(n, p);
+        if matches!(method, Method::Btpe(btpe, _) if !btpe.is_valid()) {
+            return Err(Error::NTooLarge);
         }
 
-        if p == 1.0 {
-            return Ok(Binomial {
-                method: Method::Constant(n),
-            });
+        Ok(Binomial { n, p, method })
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the probability of success `p`.
+    pub fn p(&self) -> F {
+        self.p
+    }
+}
+
+impl<F> Method<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
+    /// Select the sampling method for `0 <= p <= 1` and compute its
+    /// constants.
+    fn new(n: u64, p: F) -> Method<F> {
+        if p == F::zero() {
+            return Method::Constant(0);
+        }
+
+        if p == F::one() {
+            return Method::Constant(n);
         }
 
         // The binomial distribution is symmetrical with respect to p -> 1-p
-        let flipped = p > 0.5;
-        let p = if flipped { 1.0 - p } else { p };
+        let flipped = p > F::from(0.5).unwrap();
+        let p = if flipped { F::one() - p } else { p };
 
         // For small n * min(p, 1 - p), the BINV algorithm based on the inverse
         // transformation of the binomial distribution is efficient. Otherwise,
@@ -144,10 +186,11 @@ impl Binomial {
         // Ranlib uses 30, and GSL uses 14.
         const BINV_THRESHOLD: f64 = 10.;
 
-        let np = n as f64 * p;
-        let method = if np < BINV_THRESHOLD {
-            let q = 1.0 - p;
-            if q == 1.0 {
+        let n_f = F::from(n).unwrap();
+        let np = n_f * p;
+        if np < F::from(BINV_THRESHOLD).unwrap() {
+            let q = F::one() - p;
+            if q == F::one() {
                 // p is so small that this is extremely close to a Poisson distribution.
                 // The flipped case cannot occur here.
                 Method::Poisson(crate::poisson::KnuthMethod::new(np))
@@ -155,33 +198,62 @@ impl Binomial {
                 let s = p / q;
                 Method::Binv(
                     Binv {
-                        r: q.powf(n as f64),
+                        r: q.powf(n_f),
                         s,
-                        a: (n as f64 + 1.0) * s,
+                        a: (n_f + F::one()) * s,
                         n,
                     },
                     flipped,
                 )
             }
         } else {
-            let q = 1.0 - p;
+            let q = F::one() - p;
             let npq = np * q;
-            let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+            let p1 = (F::from(2.195).unwrap() * npq.sqrt() - F::from(4.6).unwrap() * q).floor()
+                + F::from(0.5).unwrap();
             let f_m = np + p;
-            let m = f64_to_u64(f_m);
+            let m = float_to_u64(f_m);
             Method::Btpe(Btpe { n, p, m, p1 }, flipped)
-        };
-        Ok(Binomial { method })
+        }
     }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match *self {
+            Method::Binv(binv_para, flipped) => binv(binv_para, flipped, rng),
+            Method::Btpe(btpe_para, flipped) => btpe(btpe_para, flipped, rng),
+            // saturating cast
+            Method::Poisson(poisson) => poisson.sample(rng).to_u64().unwrap_or(u64::MAX),
+            Method::Constant(c) => c,
+        }
+    }
+}
+
+/// Sample from `Binomial(n, p)` for `0 <= p <= 1`.
+///
+/// This is meant for callers with a different `p` for every sample. It
+/// performs the same setup as [`Binomial::new`] on every call and only skips
+/// the validation of `p`.
+pub(crate) fn sample_binomial<F, R>(n: u64, p: F, rng: &mut R) -> u64
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+    R: Rng + ?Sized,
+{
+    Method::new(n, p).sample(rng)
 }
 
-/// Convert a `f64` to a `u64`, panicking on overflow.
-fn f64_to_u64(x: f64) -> u64 {
-    assert!(x >= 0.0 && x < (u64::MAX as f64));
-    x as u64
+/// Convert a float to a `u64`, panicking on overflow.
+fn float_to_u64<F: Float>(x: F) -> u64 {
+    assert!(x >= F::zero() && x < F::from(u64::MAX).unwrap());
+    x.to_u64().unwrap()
 }
 
-fn binv<R: Rng + ?Sized>(binv: Binv, flipped: bool, rng: &mut R) -> u64 {
+fn binv<F, R>(binv: Binv<F>, flipped: bool, rng: &mut R) -> u64
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    R: Rng + ?Sized,
+{
     // Same value as in GSL.
     // It is possible for BINV to get stuck, so we break if x > BINV_MAX_X and try again.
     // It would be safer to set BINV_MAX_X to self.n, but it is extremely unlikely to be relevant.
@@ -190,16 +262,16 @@ fn binv<R: Rng + ?Sized>(binv: Binv, flipped: bool, rng: &mut R) -> u64 {
 
     let sample = 'outer: loop {
         let mut r = binv.r;
-        let mut u: f64 = rng.random();
+        let mut u: F = rng.random();
         let mut x = 0;
 
         while u > r {
-            u -= r;
+            u = u - r;
             x += 1;
             if x > BINV_MAX_X {
                 continue 'outer;
             }
-            r *= binv.a / (x as f64) - binv.s;
+            r = r * (binv.a / F::from(x).unwrap() - binv.s);
         }
         break x;
     };
@@ -207,47 +279,114 @@ fn binv<R: Rng + ?Sized>(binv: Binv, flipped: bool, rng: &mut R) -> u64 {
     if flipped { binv.n - sample } else { sample }
 }
 
+/// The constants of the regions of BTPE, which only depend on `n` and `p`.
+struct BtpeRegions<F> {
+    q: F,
+    npq: F,
+    x_m: F,
+    x_l: F,
+    x_r: F,
+    c: F,
+    p2: F,
+    p3: F,
+    p4: F,
+    lambda_l: F,
+    lambda_r: F,
+}
+
+impl<F: Float> Btpe<F> {
+    fn regions(&self) -> BtpeRegions<F> {
+        let one = F::one();
+        let half = F::from(0.5).unwrap();
+
+        let np = F::from(self.n).unwrap() * self.p;
+        let q = one - self.p;
+        let npq = np * q;
+        let f_m = np + self.p;
+        let m = self.m;
+        // radius of triangle region, since height=1 also area of region
+        let p1 = self.p1;
+        // tip of triangle
+        let x_m = F::from(m).unwrap() + half;
+        // left edge of triangle
+        let x_l = x_m - p1;
+        // right edge of triangle
+        let x_r = x_m + p1;
+        let c = F::from(0.134).unwrap()
+            + F::from(20.5).unwrap() / (F::from(15.3).unwrap() + F::from(m).unwrap());
+        // p1 + area of parallelogram region
+        let p2 = p1 * (one + (one + one) * c);
+
+        fn lambda<F: Float>(a: F) -> F {
+            a * (F::one() + F::from(0.5).unwrap() * a)
+        }
+
+        let lambda_l = lambda((f_m - x_l) / (f_m - x_l * self.p));
+        let lambda_r = lambda((x_r - f_m) / (x_r * q));
+
+        let p3 = p2 + c / lambda_l;
+
+        let p4 = p3 + c / lambda_r;
+
+        BtpeRegions {
+            q,
+            npq,
+            x_m,
+            x_l,
+            x_r,
+            c,
+            p2,
+            p3,
+            p4,
+            lambda_l,
+            lambda_r,
+        }
+    }
+
+    /// Whether the regions can be computed in `F`, which fails when the
+    /// spacing of `F` at the mode is not small compared to the spread.
+    fn is_valid(&self) -> bool {
+        let regions = self.regions();
+        regions.lambda_l > F::zero() && regions.lambda_r > F::zero() && regions.p4.is_finite()
+    }
+}
+
 #[allow(clippy::many_single_char_names)] // Same names as in the reference.
-fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
+fn btpe<F, R>(btpe: Btpe<F>, flipped: bool, rng: &mut R) -> u64
+where
+    F: Float + SampleUniform,
+    R: Rng + ?Sized,
+{
     // Threshold for using the squeeze algorithm. This can be freely
     // chosen based on performance. Ranlib and GSL use 20.
     const SQUEEZE_THRESHOLD: u64 = 20;
 
+    let one = F::one();
+    let half = F::from(0.5).unwrap();
+
     // Step 0: Calculate constants as functions of `n` and `p`.
     let n = btpe.n;
-    let np = (n as f64) * btpe.p;
-    let q = 1. - btpe.p;
-    let npq = np * q;
-    let f_m = np + btpe.p;
     let m = btpe.m;
-    // radius of triangle region, since height=1 also area of region
     let p1 = btpe.p1;
-    // tip of triangle
-    let x_m = (m as f64) + 0.5;
-    // left edge of triangle
-    let x_l = x_m - p1;
-    // right edge of triangle
-    let x_r = x_m + p1;
-    let c = 0.134 + 20.5 / (15.3 + (m as f64));
-    // p1 + area of parallelogram region
-    let p2 = p1 * (1. + 2. * c);
-
-    fn lambda(a: f64) -> f64 {
-        a * (1. + 0.5 * a)
-    }
-
-    let lambda_l = lambda((f_m - x_l) / (f_m - x_l * btpe.p));
-    let lambda_r = lambda((x_r - f_m) / (x_r * q));
-
-    let p3 = p2 + c / lambda_l;
-
-    let p4 = p3 + c / lambda_r;
+    let BtpeRegions {
+        q,
+        npq,
+        x_m,
+        x_l,
+        x_r,
+        c,
+        p2,
+        p3,
+        p4,
+        lambda_l,
+        lambda_r,
+    } = btpe.regions();
 
     // return value
     let mut y: u64;
 
-    let gen_u = Uniform::new(0., p4).unwrap();
-    let gen_v = Uniform::new(0., 1.).unwrap();
+    let gen_u = Uniform::new(F::zero(), p4).unwrap();
+    let gen_v = Uniform::new(F::zero(), one).unwrap();
 
     loop {
         // Step 1: Generate `u` for selecting the region. If region 1 is
@@ -255,7 +394,7 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
         let u = gen_u.sample(rng);
         let mut v = gen_v.sample(rng);
         if !(u > p1) {
-            y = f64_to_u64(x_m - p1 * v + u);
+            y = float_to_u64(x_m - p1 * v + u);
             break;
         }
 
@@ -263,28 +402,29 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
             // Step 2: Region 2, parallelograms. Check if region 2 is
             // used. If so, generate `y`.
             let x = x_l + (u - p1) / c;
-            v = v * c + 1.0 - (x - x_m).abs() / p1;
-            if v > 1. {
+            v = v * c + one - (x - x_m).abs() / p1;
+            if v > one {
                 continue;
             } else {
-                y = f64_to_u64(x);
+                y = float_to_u64(x);
             }
         } else if !(u > p3) {
             // Step 3: Region 3, left exponential tail.
             let y_tmp = x_l + v.ln() / lambda_l;
-            if y_tmp < 0.0 {
+            if y_tmp < F::zero() {
                 continue;
             } else {
-                y = f64_to_u64(y_tmp);
-                v *= (u - p2) * lambda_l;
+                y = float_to_u64(y_tmp);
+                v = v * ((u - p2) * lambda_l);
             }
         } else {
             // Step 4: Region 4, right exponential tail.
-            y = (x_r - v.ln() / lambda_r) as u64; // `as` cast saturates
+            // saturating cast
+            y = (x_r - v.ln() / lambda_r).to_u64().unwrap_or(u64::MAX);
             if y > btpe.n {
                 continue;
             } else {
-                v *= (u - p3) * lambda_r;
+                v = v * ((u - p3) * lambda_r);
             }
         }
 
@@ -292,18 +432,18 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
 
         // Step 5.0: Test for appropriate method of evaluating f(y).
         let k = y.abs_diff(m);
-        if !(k > SQUEEZE_THRESHOLD && (k as f64) < 0.5 * npq - 1.) {
+        if !(k > SQUEEZE_THRESHOLD && F::from(k).unwrap() < half * npq - one) {
             // Step 5.1: Evaluate f(y) via the recursive relationship. Start the
             // search from the mode.
             let s = btpe.p / q;
-            let a = s * (n as f64 + 1.);
-            let mut f = 1.0;
+            let a = s * (F::from(n).unwrap() + one);
+            let mut f = one;
             match m.cmp(&y) {
                 Ordering::Less => {
                     let mut i = m;
                     loop {
                         i += 1;
-                        f *= a / (i as f64) - s;
+                        f = f * (a / F::from(i).unwrap() - s);
                         if i == y {
                             break;
                         }
@@ -313,7 +453,7 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
                     let mut i = y;
                     loop {
                         i += 1;
-                        f /= a / (i as f64) - s;
+                        f = f / (a / F::from(i).unwrap() - s);
                         if i == m {
                             break;
                         }
@@ -330,9 +470,13 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
 
         // Step 5.2: Squeezing. Check the value of ln(v) against upper and
         // lower bound of ln(f(y)).
-        let k = k as f64;
-        let rho = (k / npq) * ((k * (k / 3. + 0.625) + 1. / 6.) / npq + 0.5);
-        let t = -0.5 * k * k / npq;
+        let k = F::from(k).unwrap();
+        let rho = (k / npq)
+            * ((k * (k / F::from(3.).unwrap() + F::from(0.625).unwrap())
+                + one / F::from(6.).unwrap())
+                / npq
+                + half);
+        let t = -half * k * k / npq;
         let alpha = v.ln();
         if alpha < t - rho {
             break;
@@ -342,24 +486,27 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
         }
 
         // Step 5.3: Final acceptance/rejection test.
-        let x1 = (y + 1) as f64;
-        let f1 = (m + 1) as f64;
-        let z = ((n - m) + 1) as f64;
-        let w = ((n - y) + 1) as f64;
+        let x1 = F::from(y + 1).unwrap();
+        let f1 = F::from(m + 1).unwrap();
+        let z = F::from((n - m) + 1).unwrap();
+        let w = F::from((n - y) + 1).unwrap();
 
-        fn stirling(a: f64) -> f64 {
+        fn stirling<F: Float>(a: F) -> F {
             let a2 = a * a;
-            (13860. - (462. - (132. - (99. - 140. / a2) / a2) / a2) / a2) / a / 166320.
+            let c = |x: f64| F::from(x).unwrap();
+            (c(13860.) - (c(462.) - (c(132.) - (c(99.) - c(140.) / a2) / a2) / a2) / a2)
+                / a
+                / c(166320.)
         }
 
         let y_sub_m = if y > m {
-            (y - m) as f64
+            F::from(y - m).unwrap()
         } else {
-            -((m - y) as f64)
+            -F::from(m - y).unwrap()
         };
         if alpha
             > x_m * (f1 / x1).ln()
-                + (((n - m) as f64) + 0.5) * (z / w).ln()
+                + (F::from(n - m).unwrap() + half) * (z / w).ln()
                 + y_sub_m * (w * btpe.p / (x1 * q)).ln()
                 // We use the signs from the GSL implementation, which are
                 // different than the ones in the reference. According to
@@ -380,14 +527,13 @@ fn btpe<R: Rng + ?Sized>(btpe: Btpe, flipped: bool, rng: &mut R) -> u64 {
     if flipped { btpe.n - y } else { y }
 }
 
-impl Distribution<u64> for Binomial {
+impl<F> Distribution<u64> for Binomial<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
-        match self.method {
-            Method::Binv(binv_para, flipped) => binv(binv_para, flipped, rng),
-            Method::Btpe(btpe_para, flipped) => btpe(btpe_para, flipped, rng),
-            Method::Poisson(poisson) => poisson.sample(rng) as u64,
-            Method::Constant(c) => c,
-        }
+        self.method.sample(rng)
     }
 }
 
@@ -395,8 +541,14 @@ impl Distribution<u64> for Binomial {
 mod test {
     use super::*;
 
-    fn test_binomial_mean_and_variance<R: Rng>(n: u64, p: f64, rng: &mut R) {
+    fn test_binomial_mean_and_variance<F, R>(n: u64, p: F, rng: &mut R)
+    where
+        F: Float + SampleUniform,
+        StandardUniform: Distribution<F>,
+        R: Rng,
+    {
         let binomial = Binomial::new(n, p).unwrap();
+        let p = p.to_f64().unwrap();
 
         let expected_mean = n as f64 * p;
         let expected_variance = n as f64 * p * (1.0 - p);
@@ -426,6 +578,32 @@ mod test {
         test_binomial_mean_and_variance(u64::MAX, 1e-19, &mut rng);
     }
 
+    #[test]
+    fn test_binomial_f32() {
+        let mut rng = crate::test::rng(354);
+        test_binomial_mean_and_variance(150, 0.1f32, &mut rng);
+        test_binomial_mean_and_variance(70, 0.6f32, &mut rng);
+        test_binomial_mean_and_variance(20, 0.5f32, &mut rng);
+        test_binomial_mean_and_variance(1 << 40, 1e-11f32, &mut rng);
+    }
+
+    #[test]
+    fn test_binomial_f32_large_n() {
+        let mut rng = crate::test::rng(355);
+        let n = 1u64 << 51;
+        let mean = n as f64 * 0.3;
+        let d = Binomial::new(n, 0.3f32).unwrap();
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng) as f64;
+            assert!((x - mean).abs() < mean * 1e-5);
+        }
+
+        assert_eq!(Binomial::new(1 << 53, 0.3f32), Err(Error::NTooLarge));
+        assert_eq!(Binomial::new(1 << 56, 0.3f32), Err(Error::NTooLarge));
+        assert_eq!(Binomial::new(u64::MAX, 0.5f32), Err(Error::NTooLarge));
+        assert!(Binomial::new(u64::MAX, 0.5f64).is_ok());
+    }
+
     #[test]
     fn test_binomial_end_points() {
         let mut rng = crate::test::rng(352);
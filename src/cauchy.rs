@@ -113,6 +113,63 @@ where
     }
 }
 
+/// The [half-Cauchy distribution](https://en.wikipedia.org/wiki/Cauchy_distribution#Related_distributions) `HalfCauchy(γ)`.
+///
+/// This is the distribution of `|X|` where `X ~ Cauchy(0, γ)`, i.e. the
+/// [`Cauchy`] distribution with median zero folded onto the non-negative
+/// reals. It is a common weakly informative prior for scale parameters.
+///
+/// # Density function
+///
+/// `f(x) = 2 / (π * γ * (1 + (x / γ)²))` for `x >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{HalfCauchy, Distribution};
+///
+/// let cau = HalfCauchy::new(2.5).unwrap();
+/// let v = cau.sample(&mut rand::rng());
+/// println!("{} is from a HalfCauchy(2.5) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalfCauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    cauchy: Cauchy<F>,
+}
+
+impl<F> HalfCauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    /// Construct a new `HalfCauchy` with the given `scale`.
+    pub fn new(scale: F) -> Result<HalfCauchy<F>, Error> {
+        Ok(HalfCauchy {
+            cauchy: Cauchy::new(F::zero(), scale)?,
+        })
+    }
+
+    /// Returns the scale (`γ`) of the distribution.
+    pub fn scale(&self) -> F {
+        self.cauchy.scale
+    }
+}
+
+impl<F> Distribution<F> for HalfCauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.cauchy.sample(rng).abs()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -202,4 +259,16 @@ mod test {
     fn cauchy_distributions_can_be_compared() {
         assert_eq!(Cauchy::new(1.0, 2.0), Cauchy::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_half_cauchy() {
+        assert!(HalfCauchy::new(0.0).is_err());
+        let d = HalfCauchy::new(2.5).unwrap();
+        assert_eq!(d.scale(), 2.5);
+        let mut rng = crate::test::rng(124);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.0);
+        }
+        assert_eq!(HalfCauchy::new(1.0), HalfCauchy::new(1.0));
+    }
 }
@@ -0,0 +1,127 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Chi distribution `χ(k)`.
+
+use crate::{ChiSquared, Distribution, Exp1, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [chi distribution](https://en.wikipedia.org/wiki/Chi_distribution) `χ(k)`.
+///
+/// The chi distribution is a continuous probability distribution with
+/// parameter `k > 0` degrees of freedom. It is the distribution of the
+/// square root of a [`ChiSquared`](crate::ChiSquared) random variable; for
+/// integral `k`, this is the Euclidean norm of a vector of `k` independent
+/// standard normal random variables.
+///
+/// For `k = 2` this is the standard Rayleigh distribution, and for `k = 3`
+/// the [`MaxwellBoltzmann`](crate::MaxwellBoltzmann) distribution with
+/// scale 1.
+///
+/// # Density function
+///
+/// `f(x) = x^(k - 1) * exp(-x² / 2) / (2^(k/2 - 1) * Γ(k/2))` for `x >= 0`,
+/// where `Γ` is the [gamma function](https://en.wikipedia.org/wiki/Gamma_function).
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Chi, Distribution};
+///
+/// let chi = Chi::new(11.0).unwrap();
+/// let v = chi.sample(&mut rand::rng());
+/// println!("{} is from a χ(11) distribution", v)
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    chi_squared: ChiSquared<F>,
+}
+
+/// Error type returned from [`Chi::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Error {
+    /// `k <= 0` or `nan`.
+    DoFTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::DoFTooSmall => "degrees-of-freedom k is not positive in chi distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Create a new chi distribution with degrees-of-freedom `k`.
+    pub fn new(k: F) -> Result<Chi<F>, Error> {
+        let chi_squared = ChiSquared::new(k).map_err(|_| Error::DoFTooSmall)?;
+        Ok(Chi { chi_squared })
+    }
+}
+
+impl<F> Distribution<F> for Chi<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.chi_squared.sample(rng).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chi() {
+        let mut rng = crate::test::rng(231);
+        for k in [0.5, 1.0, 3.0, 30.0] {
+            let chi = Chi::new(k).unwrap();
+            for _ in 0..1000 {
+                assert!(chi.sample(&mut rng) >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chi_invalid_dof() {
+        assert_eq!(Chi::new(0.0), Err(Error::DoFTooSmall));
+        assert_eq!(Chi::new(-1.0), Err(Error::DoFTooSmall));
+        assert_eq!(Chi::new(f64::NAN), Err(Error::DoFTooSmall));
+    }
+
+    #[test]
+    fn chi_distributions_can_be_compared() {
+        assert_eq!(Chi::new(1.0), Chi::new(1.0));
+    }
+}
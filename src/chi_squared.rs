@@ -56,7 +56,7 @@ where
     repr: ChiSquaredRepr<F>,
 }
 
-/// Error type returned from [`ChiSquared::new`] and [`StudentT::new`](crate::StudentT::new).
+/// Error type returned from [`ChiSquared::new`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Error {
@@ -0,0 +1,348 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The compound Poisson and Tweedie distributions.
+
+use crate::{
+    Distribution, Exp1, Gamma, Open01, Poisson, PoissonError, StandardNormal, StandardUniform,
+};
+use core::fmt;
+use num_traits::{Float, FloatConst};
+use rand::Rng;
+
+/// The [compound Poisson distribution](https://en.wikipedia.org/wiki/Compound_Poisson_distribution)
+/// `CP(λ, D)`.
+///
+/// This is the distribution of the random sum `S = X_1 + ... + X_N`, where
+/// `N ~ Poisson(λ)` and the `X_i` are independent samples of the severity
+/// distribution `D`. With probability `exp(-λ)`, `N = 0` and `S = 0`.
+/// It is the classical model of the aggregate loss of an insurance
+/// portfolio.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{CompoundPoisson, Distribution, LogNormal};
+///
+/// // On average 3.5 claims per year with log-normal claim sizes
+/// let claims = LogNormal::new(7.0, 1.2).unwrap();
+/// let aggregate = CompoundPoisson::new(3.5, claims).unwrap();
+/// let v = aggregate.sample(&mut rand::rng());
+/// println!("{} is the aggregate loss of one year", v);
+/// ```
+///
+/// # Notes
+///
+/// Sampling takes time proportional to the sampled `N`, i.e. `λ` on
+/// average. For gamma-distributed severities, [`Tweedie`] samples in
+/// constant time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompoundPoisson<F, D>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    poisson: Poisson<F>,
+    severity: D,
+}
+
+impl<F, D> CompoundPoisson<F, D>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    /// Construct a new `CompoundPoisson` distribution with Poisson rate
+    /// `lambda` and severity distribution `severity`.
+    ///
+    /// The constraints on `lambda` are those of [`Poisson::new`].
+    pub fn new(lambda: F, severity: D) -> Result<CompoundPoisson<F, D>, PoissonError> {
+        Ok(CompoundPoisson {
+            poisson: Poisson::new(lambda)?,
+            severity,
+        })
+    }
+
+    /// Returns the Poisson rate `lambda` of the number of summands.
+    pub fn lambda(&self) -> F {
+        self.poisson.lambda()
+    }
+
+    /// Returns a reference to the severity distribution.
+    pub fn severity(&self) -> &D {
+        &self.severity
+    }
+}
+
+impl<F, D> Distribution<F> for CompoundPoisson<F, D>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    D: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // Count in an integer type, since `F` cannot decrement large counts
+        let n: u64 = self.poisson.sample(rng);
+        let mut sum = F::zero();
+        for _ in 0..n {
+            sum = sum + self.severity.sample(rng);
+        }
+        sum
+    }
+}
+
+/// The [Tweedie distribution](https://en.wikipedia.org/wiki/Tweedie_distribution)
+/// `Tw_p(μ, φ)` with power parameter `1 < p < 2`.
+///
+/// This member of the Tweedie family of exponential dispersion models has
+/// mean `μ` and variance `φ μ^p`. It is the compound Poisson-gamma
+/// distribution of the sum of `N ~ Poisson(λ)` independent
+/// `Gamma(α, θ)` random variables, where
+///
+/// - `λ = μ^(2 - p) / (φ (2 - p))`,
+/// - `α = (2 - p) / (p - 1)`,
+/// - `θ = φ (p - 1) μ^(p - 1)`.
+///
+/// It has a point mass `exp(-λ)` at zero and is otherwise continuous, which
+/// makes it a common model of insurance claim amounts and rainfall.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Tweedie};
+///
+/// let tweedie = Tweedie::new(2.0, 1.5, 1.5).unwrap();
+/// let v = tweedie.sample(&mut rand::rng());
+/// println!("{} is from a Tweedie distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Given `N = n > 0`, the sum is sampled directly as `Gamma(n α, θ)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tweedie<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    mean: F,
+    dispersion: F,
+    power: F,
+    poisson: Poisson<F>,
+    shape: F,
+    scale: F,
+}
+
+/// Error type returned from [`Tweedie::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `mean <= 0`, infinite or `nan`.
+    MeanNotPositive,
+    /// `dispersion <= 0`, infinite or `nan`.
+    DispersionNotPositive,
+    /// `power` is not in the open interval `(1, 2)`.
+    PowerOutOfRange,
+    /// The Poisson rate `λ` or gamma scale `θ` is zero or not finite, or
+    /// `λ` exceeds [`Poisson::MAX_LAMBDA`].
+    ParametersOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::MeanNotPositive => "mean is not positive and finite in Tweedie distribution",
+            Error::DispersionNotPositive => {
+                "dispersion is not positive and finite in Tweedie distribution"
+            }
+            Error::PowerOutOfRange => "power is not in (1, 2) in Tweedie distribution",
+            Error::ParametersOutOfRange => {
+                "Poisson rate or gamma scale is out of range in Tweedie distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Tweedie<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `Tweedie` distribution with the given `mean` (`μ`),
+    /// `dispersion` (`φ`) and `power` (`p`).
+    ///
+    /// Parameters:
+    ///
+    /// -   `mean` (must be positive and finite)
+    /// -   `dispersion` (must be positive and finite)
+    /// -   `power` (must satisfy `1 < power < 2`)
+    pub fn new(mean: F, dispersion: F, power: F) -> Result<Tweedie<F>, Error> {
+        if !(mean > F::zero()) || mean.is_infinite() {
+            return Err(Error::MeanNotPositive);
+        }
+        if !(dispersion > F::zero()) || dispersion.is_infinite() {
+            return Err(Error::DispersionNotPositive);
+        }
+        let two = F::from(2.0).unwrap();
+        if !(power > F::one() && power < two) {
+            return Err(Error::PowerOutOfRange);
+        }
+
+        let lambda = mean.powf(two - power) / (dispersion * (two - power));
+        let shape = (two - power) / (power - F::one());
+        let scale = dispersion * (power - F::one()) * mean.powf(power - F::one());
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(Error::ParametersOutOfRange);
+        }
+        let poisson = Poisson::new(lambda).map_err(|_| Error::ParametersOutOfRange)?;
+
+        Ok(Tweedie {
+            mean,
+            dispersion,
+            power,
+            poisson,
+            shape,
+            scale,
+        })
+    }
+
+    /// Returns the mean (`μ`) of the distribution.
+    pub fn mean(&self) -> F {
+        self.mean
+    }
+
+    /// Returns the dispersion (`φ`) of the distribution.
+    pub fn dispersion(&self) -> F {
+        self.dispersion
+    }
+
+    /// Returns the power parameter (`p`) of the distribution.
+    pub fn power(&self) -> F {
+        self.power
+    }
+}
+
+impl<F> Distribution<F> for Tweedie<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let n: F = self.poisson.sample(rng);
+        if n == F::zero() {
+            return F::zero();
+        }
+        // The sum of n independent Gamma(α, θ) variables is Gamma(n α, θ)
+        Gamma::new(n * self.shape, self.scale).unwrap().sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Exp;
+
+    #[test]
+    fn invalid() {
+        assert!(CompoundPoisson::new(0.0, Exp1).is_err());
+        assert!(CompoundPoisson::new(f64::NAN, Exp1).is_err());
+
+        assert_eq!(Tweedie::new(0.0, 1.0, 1.5), Err(Error::MeanNotPositive));
+        assert_eq!(
+            Tweedie::new(f64::INFINITY, 1.0, 1.5),
+            Err(Error::MeanNotPositive)
+        );
+        assert_eq!(
+            Tweedie::new(1.0, -1.0, 1.5),
+            Err(Error::DispersionNotPositive)
+        );
+        assert_eq!(Tweedie::new(1.0, 1.0, 1.0), Err(Error::PowerOutOfRange));
+        assert_eq!(Tweedie::new(1.0, 1.0, 2.0), Err(Error::PowerOutOfRange));
+        assert_eq!(
+            Tweedie::new(1.0, 1.0, f64::NAN),
+            Err(Error::PowerOutOfRange)
+        );
+        assert_eq!(
+            Tweedie::new(1e-300, 1e300, 1.5),
+            Err(Error::ParametersOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_compound_poisson() {
+        let d = CompoundPoisson::new(4.0, Exp::new(0.5).unwrap()).unwrap();
+        assert_eq!(d.lambda(), 4.0);
+        assert_eq!(d.severity(), &Exp::new(0.5).unwrap());
+        let mut rng = crate::test::rng(391);
+        let (mut sum, mut zeros) = (0.0, 0);
+        for _ in 0..10000 {
+            let x = d.sample(&mut rng);
+            assert!(x >= 0.0);
+            sum += x;
+            if x == 0.0 {
+                zeros += 1;
+            }
+        }
+        // Mean λ E[X] = 8, and P(S = 0) = exp(-4) ≈ 0.018
+        assert!((sum / 10000.0 - 8.0).abs() < 0.2);
+        assert!((120..250).contains(&zeros));
+    }
+
+    #[test]
+    fn large_lambda() {
+        // A float counter would get stuck above 2^24 for f32
+        struct Half;
+        impl Distribution<f32> for Half {
+            fn sample<R: Rng + ?Sized>(&self, _: &mut R) -> f32 {
+                0.5
+            }
+        }
+
+        let d = CompoundPoisson::new(3e7f32, Half).unwrap();
+        let mut rng = crate::test::rng(393);
+        assert!(d.sample(&mut rng) >= 4e6);
+    }
+
+    #[test]
+    fn test_tweedie() {
+        let d = Tweedie::new(2.0, 1.5, 1.5).unwrap();
+        assert_eq!((d.mean(), d.dispersion(), d.power()), (2.0, 1.5, 1.5));
+        let mut rng = crate::test::rng(392);
+        let mut sum = 0.0;
+        for _ in 0..10000 {
+            let x = d.sample(&mut rng);
+            assert!(x >= 0.0);
+            sum += x;
+        }
+        // Variance φ μ^p ≈ 4.24, so the standard error of the mean is ≈ 0.02
+        assert!((sum / 10000.0 - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn compound_poisson_distributions_can_be_compared() {
+        assert_eq!(
+            CompoundPoisson::new(1.0, Exp::new(2.0).unwrap()),
+            CompoundPoisson::new(1.0, Exp::new(2.0).unwrap())
+        );
+        assert_eq!(Tweedie::new(1.0, 2.0, 1.5), Tweedie::new(1.0, 2.0, 1.5));
+    }
+}
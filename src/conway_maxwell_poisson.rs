@@ -0,0 +1,260 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Conway–Maxwell–Poisson distribution `ConwayMaxwellPoisson(λ, ν)`.
+
+use crate::utils::ln_gamma;
+use crate::{Distribution, Geometric, Poisson};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Conway–Maxwell–Poisson distribution](https://en.wikipedia.org/wiki/Conway%E2%80%93Maxwell%E2%80%93Poisson_distribution)
+/// `ConwayMaxwellPoisson(λ, ν)`.
+///
+/// This generalization of the [`Poisson`] distribution models both under-
+/// and over-dispersed counts: the variance is smaller than the mean for
+/// `ν > 1` and larger for `ν < 1`. For `ν = 1` it is `Poisson(λ)`.
+///
+/// # Density function
+///
+/// `f(k) = λ^k / (k!)^ν / Z(λ, ν)` for `k >= 0`, where `Z(λ, ν)` is the
+/// normalizing constant.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{ConwayMaxwellPoisson, Distribution};
+///
+/// let cmp = ConwayMaxwellPoisson::new(4.0, 1.5).unwrap();
+/// let v = cmp.sample(&mut rand::rng());
+/// println!("{} is from a Conway-Maxwell-Poisson distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated with the exact rejection sampler of Benson and
+/// Friel, with a [`Poisson`] envelope for `ν >= 1` and a [`Geometric`]
+/// envelope for `ν < 1`:
+///
+/// A. Benson and N. Friel. 2021. Bayesian Inference, Model Selection and
+/// Likelihood Estimation using Fast Rejection Sampling: The
+/// Conway-Maxwell-Poisson Distribution. Bayesian Analysis 16, 3 (2021),
+/// 905–931. <https://doi.org/10.1214/20-BA1230>
+///
+/// The acceptance rate decreases for `ν` far from 1 and large `λ^(1 / ν)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConwayMaxwellPoisson {
+    lambda: f64,
+    nu: f64,
+    // ln(λ^(1/ν))
+    ln_mu: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    PoissonEnvelope {
+        poisson: Poisson<f64>,
+        ln_bound: f64,
+    },
+    GeometricEnvelope {
+        geometric: Geometric,
+        // ln(1 - p) of the geometric distribution
+        ln_q: f64,
+        ln_bound: f64,
+    },
+}
+
+/// Error type returned from [`ConwayMaxwellPoisson::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `lambda <= 0`, `lambda = ∞` or `nan`.
+    LambdaNotPositive,
+    /// `nu <= 0`, `nu = ∞` or `nan`.
+    NuNotPositive,
+    /// `lambda^(1 / nu)` exceeds [`Poisson::MAX_LAMBDA`].
+    LambdaTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LambdaNotPositive => {
+                "lambda is not positive and finite in Conway-Maxwell-Poisson distribution"
+            }
+            Error::NuNotPositive => {
+                "nu is not positive and finite in Conway-Maxwell-Poisson distribution"
+            }
+            Error::LambdaTooLarge => {
+                "lambda^(1 / nu) is too large in Conway-Maxwell-Poisson distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl ConwayMaxwellPoisson {
+    /// Construct a new `ConwayMaxwellPoisson` distribution with rate
+    /// parameter `lambda` and dispersion parameter `nu`.
+    pub fn new(lambda: f64, nu: f64) -> Result<ConwayMaxwellPoisson, Error> {
+        if !(lambda > 0.0 && lambda.is_finite()) {
+            return Err(Error::LambdaNotPositive);
+        }
+        if !(nu > 0.0 && nu.is_finite()) {
+            return Err(Error::NuNotPositive);
+        }
+        let ln_mu = lambda.ln() / nu;
+        let mu = ln_mu.exp();
+        if !(mu <= Poisson::<f64>::MAX_LAMBDA) {
+            return Err(Error::LambdaTooLarge);
+        }
+
+        let method = if nu >= 1.0 {
+            // The ratio of the target to the Poisson(μ) density is
+            // proportional to (μ^k / k!)^(ν - 1), which is maximal at the
+            // mode floor(μ) of the Poisson distribution.
+            let mode = mu.floor();
+            Method::PoissonEnvelope {
+                poisson: Poisson::new(mu).map_err(|_| Error::LambdaTooLarge)?,
+                ln_bound: (nu - 1.0) * (mode * ln_mu - ln_gamma(mode + 1.0)),
+            }
+        } else {
+            // The ratio of the target to the geometric density is
+            // proportional to (μ^k / k!)^ν / q^k, which is maximal at
+            // floor(μ / q^(1/ν)).
+            let p = 2.0 * nu / (2.0 * mu * nu + 1.0 + nu);
+            let ln_q = (-p).ln_1p();
+            let mode = (ln_mu - ln_q / nu).exp().floor();
+            Method::GeometricEnvelope {
+                geometric: Geometric::new(p).unwrap(),
+                ln_q,
+                ln_bound: nu * (mode * ln_mu - ln_gamma(mode + 1.0)) - mode * ln_q,
+            }
+        };
+
+        Ok(ConwayMaxwellPoisson {
+            lambda,
+            nu,
+            ln_mu,
+            method,
+        })
+    }
+
+    /// Returns the rate parameter `lambda`.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Returns the dispersion parameter `nu`.
+    pub fn nu(&self) -> f64 {
+        self.nu
+    }
+}
+
+impl Distribution<u64> for ConwayMaxwellPoisson {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let (k, ln_ratio) = match &self.method {
+                Method::PoissonEnvelope { poisson, ln_bound } => {
+                    let k: f64 = poisson.sample(rng);
+                    let ln_ratio = (self.nu - 1.0) * (k * self.ln_mu - ln_gamma(k + 1.0));
+                    (k, ln_ratio - ln_bound)
+                }
+                Method::GeometricEnvelope {
+                    geometric,
+                    ln_q,
+                    ln_bound,
+                } => {
+                    let k = geometric.sample(rng) as f64;
+                    let ln_ratio = self.nu * (k * self.ln_mu - ln_gamma(k + 1.0)) - k * ln_q;
+                    (k, ln_ratio - ln_bound)
+                }
+            };
+            let u: f64 = rng.random();
+            if u.ln() <= ln_ratio {
+                return k as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        for lambda in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                ConwayMaxwellPoisson::new(lambda, 1.0),
+                Err(Error::LambdaNotPositive)
+            );
+        }
+        for nu in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                ConwayMaxwellPoisson::new(1.0, nu),
+                Err(Error::NuNotPositive)
+            );
+        }
+        assert_eq!(
+            ConwayMaxwellPoisson::new(1e10, 0.1),
+            Err(Error::LambdaTooLarge)
+        );
+        let cmp = ConwayMaxwellPoisson::new(2.0, 0.5).unwrap();
+        assert_eq!((cmp.lambda(), cmp.nu()), (2.0, 0.5));
+    }
+
+    #[test]
+    fn test_poisson_case() {
+        let mut rng = crate::test::rng(471);
+        let d = ConwayMaxwellPoisson::new(7.0, 1.0).unwrap();
+        let mut results = [0.0; 10000];
+        for i in results.iter_mut() {
+            *i = d.sample(&mut rng) as f64;
+        }
+
+        let mean = results.iter().sum::<f64>() / results.len() as f64;
+        assert!((mean - 7.0).abs() < 0.1);
+
+        let variance =
+            results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+        assert!((variance - 7.0).abs() < 0.7);
+    }
+
+    #[test]
+    fn test_dispersion() {
+        let mut rng = crate::test::rng(472);
+        for (lambda, nu) in [(20.0, 2.0), (3.0, 0.5)] {
+            let d = ConwayMaxwellPoisson::new(lambda, nu).unwrap();
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            // the variance is approximately the mean divided by nu
+            assert!((variance * nu / mean - 1.0).abs() < 0.2);
+        }
+    }
+
+    #[test]
+    fn conway_maxwell_poisson_distributions_can_be_compared() {
+        assert_eq!(
+            ConwayMaxwellPoisson::new(1.0, 2.0),
+            ConwayMaxwellPoisson::new(1.0, 2.0)
+        );
+    }
+}
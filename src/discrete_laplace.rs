@@ -0,0 +1,176 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The discrete Laplace distribution `DiscreteLaplace(p)`.
+
+use crate::{Distribution, Geometric};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The discrete Laplace distribution `DiscreteLaplace(p)`, also known as
+/// the two-sided geometric distribution.
+///
+/// This is the distribution of the difference of two independent
+/// [`Geometric`] random variables with probability of success `1 - p`. It
+/// is the discrete analogue of the [Laplace
+/// distribution](https://en.wikipedia.org/wiki/Laplace_distribution) and
+/// the noise distribution of the geometric mechanism of differential
+/// privacy, where `p = exp(-ε / Δ)` for privacy budget `ε` and sensitivity
+/// `Δ`, see [`DiscreteLaplace::from_scale`].
+///
+/// # Density function
+///
+/// `f(k) = (1 - p) / (1 + p) p^|k|` for all integers `k`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{DiscreteLaplace, Distribution};
+///
+/// let noise = DiscreteLaplace::new(0.5).unwrap();
+/// let v: i64 = noise.sample(&mut rand::rng());
+/// println!("{} is from a discrete Laplace distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples exceeding the range of `i64`, which are only possible for `p`
+/// extremely close to 1, saturate to `i64::MIN` or `i64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscreteLaplace {
+    p: f64,
+    geometric: Geometric,
+}
+
+/// Error type returned from [`DiscreteLaplace::new`] and
+/// [`DiscreteLaplace::from_scale`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `p < 0`, `p >= 1` or `nan`.
+    InvalidProbability,
+    /// `scale < 0` or `nan`.
+    InvalidScale,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidProbability => {
+                "p is NaN or outside the interval [0, 1) in discrete Laplace distribution"
+            }
+            Error::InvalidScale => "scale is negative or NaN in discrete Laplace distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl DiscreteLaplace {
+    /// Construct a new `DiscreteLaplace` distribution with parameter `p`,
+    /// the ratio of the probabilities of `k + 1` and `k` for `k >= 0`.
+    ///
+    /// For `p = 0` the distribution is constant zero.
+    pub fn new(p: f64) -> Result<DiscreteLaplace, Error> {
+        if !(0.0..1.0).contains(&p) {
+            return Err(Error::InvalidProbability);
+        }
+        Ok(DiscreteLaplace {
+            p,
+            geometric: Geometric::new(1.0 - p).unwrap(),
+        })
+    }
+
+    /// Construct a new `DiscreteLaplace` distribution with the given
+    /// `scale`, i.e. `p = exp(-1 / scale)`.
+    ///
+    /// As for the continuous Laplace distribution, the density decays by a
+    /// factor of `e` per `scale`.
+    pub fn from_scale(scale: f64) -> Result<DiscreteLaplace, Error> {
+        if !(scale >= 0.0) {
+            return Err(Error::InvalidScale);
+        }
+        DiscreteLaplace::new((-1.0 / scale).exp())
+    }
+
+    /// Returns the parameter `p`.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Distribution<i64> for DiscreteLaplace {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
+        let g1 = self.geometric.sample(rng);
+        let g2 = self.geometric.sample(rng);
+        if g1 >= g2 {
+            0i64.saturating_add_unsigned(g1 - g2)
+        } else {
+            0i64.saturating_sub_unsigned(g2 - g1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        for p in [-0.1, 1.0, 2.0, f64::NAN] {
+            assert_eq!(DiscreteLaplace::new(p), Err(Error::InvalidProbability));
+        }
+        assert_eq!(DiscreteLaplace::from_scale(-1.0), Err(Error::InvalidScale));
+        assert_eq!(
+            DiscreteLaplace::from_scale(f64::NAN),
+            Err(Error::InvalidScale)
+        );
+        assert_eq!(
+            DiscreteLaplace::from_scale(f64::INFINITY),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(DiscreteLaplace::from_scale(0.0).unwrap().p(), 0.0);
+    }
+
+    #[test]
+    fn test_p_zero() {
+        let d = DiscreteLaplace::new(0.0).unwrap();
+        let mut rng = crate::test::rng(452);
+        for _ in 0..10 {
+            assert_eq!(d.sample(&mut rng), 0);
+        }
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(453);
+        for p in [0.3, 0.9] {
+            let d = DiscreteLaplace::new(p).unwrap();
+            let expected_variance = 2.0 * p / ((1.0 - p) * (1.0 - p));
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!(mean.abs() < expected_variance.sqrt() / 20.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+        }
+    }
+
+    #[test]
+    fn discrete_laplace_distributions_can_be_compared() {
+        assert_eq!(DiscreteLaplace::new(0.5), DiscreteLaplace::new(0.5));
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The empirical distribution of a sample, optionally smoothed.
+
+use crate::{Distribution, StandardNormal, StandardUniform};
+use alloc::vec::Vec;
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [empirical distribution](https://en.wikipedia.org/wiki/Empirical_distribution_function)
+/// of a sample, optionally smoothed.
+///
+/// By default, sampling draws one of the observations uniformly at random
+/// (with replacement), as used by the bootstrap. Alternatively, samples can
+/// be drawn from
+///
+/// - the [linear interpolation of the empirical distribution
+///   function][Empirical::interpolated], a continuous distribution on
+///   `[min, max]` of the observations, or
+/// - a [kernel density estimate][Empirical::with_kernel] (the smoothed
+///   bootstrap), which adds noise distributed according to a [`Kernel`]
+///   scaled by a [`Bandwidth`] to the resampled observation.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Bandwidth, Distribution, Empirical, Kernel};
+///
+/// let observations = vec![1.2, 0.7, 2.5, 1.9, 1.1, 0.4];
+/// let d = Empirical::new(observations.clone()).unwrap();
+/// assert!(observations.contains(&d.sample(&mut rand::rng())));
+/// assert_eq!(d.quantile(0.5), 1.15);
+///
+/// let kde = d.with_kernel(Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+/// let v = kde.sample(&mut rand::rng());
+/// println!("{} is from a kernel density estimate", v);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Empirical<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    samples: Vec<F>,
+    smoothing: Smoothing<F>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Smoothing<F> {
+    None,
+    Interpolated,
+    Kernel(Kernel, F),
+}
+
+/// Kernel of a kernel density estimate, see [`Empirical::with_kernel`].
+///
+/// Both kernels are scaled to unit variance, such that the bandwidth is the
+/// standard deviation of the noise added to a resampled observation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kernel {
+    /// The [`StandardNormal`] kernel.
+    Gaussian,
+    /// The Epanechnikov kernel `f(x) = 3 / (4 sqrt(5)) * (1 - x² / 5)` on
+    /// `[-sqrt(5), sqrt(5)]`.
+    Epanechnikov,
+}
+
+/// Bandwidth of a kernel density estimate, see [`Empirical::with_kernel`].
+///
+/// The rules of thumb are derived for a normal distribution of the
+/// observations, with `σ` the sample standard deviation, `IQR` the
+/// interquartile range and `n` the number of observations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bandwidth<F> {
+    /// Silverman's rule of thumb, `0.9 min(σ, IQR / 1.34) n^(-1/5)`.
+    ///
+    /// If `IQR` is zero, `σ` is used instead.
+    Silverman,
+    /// Scott's rule of thumb, `1.06 σ n^(-1/5)`.
+    Scott,
+    /// A fixed bandwidth.
+    Fixed(F),
+}
+
+/// Error type returned from [`Empirical::new`] and [`Empirical::with_kernel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// There are no observations.
+    NoSamples,
+    /// An observation is infinite or `nan`.
+    SampleNotFinite,
+    /// The fixed bandwidth is negative, infinite or `nan`.
+    InvalidBandwidth,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NoSamples => "no observations given for empirical distribution",
+            Error::SampleNotFinite => "observation is not finite in empirical distribution",
+            Error::InvalidBandwidth => {
+                "bandwidth is negative or not finite in empirical distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Empirical<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct the empirical distribution of `samples`, which must be
+    /// non-empty and finite.
+    ///
+    /// Sampling resamples the observations until a different mode is chosen
+    /// with [`Empirical::interpolated`] or [`Empirical::with_kernel`].
+    pub fn new(mut samples: Vec<F>) -> Result<Empirical<F>, Error> {
+        if samples.is_empty() {
+            return Err(Error::NoSamples);
+        }
+        if !samples.iter().all(|x| x.is_finite()) {
+            return Err(Error::SampleNotFinite);
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(Empirical {
+            samples,
+            smoothing: Smoothing::None,
+        })
+    }
+
+    /// Sample from the linear interpolation of the empirical distribution
+    /// function, i.e. evaluate [`Empirical::quantile`] at a uniform random
+    /// probability.
+    ///
+    /// Note that an observation which occurs repeatedly is sampled with
+    /// positive probability.
+    pub fn interpolated(self) -> Empirical<F> {
+        Empirical {
+            smoothing: Smoothing::Interpolated,
+            ..self
+        }
+    }
+
+    /// Sample from a kernel density estimate with the given `kernel` and
+    /// `bandwidth`.
+    ///
+    /// A bandwidth of zero is equivalent to plain resampling.
+    pub fn with_kernel(
+        self,
+        kernel: Kernel,
+        bandwidth: Bandwidth<F>,
+    ) -> Result<Empirical<F>, Error> {
+        let n = F::from(self.samples.len()).unwrap();
+        let n_factor = n.powf(F::from(-0.2).unwrap());
+        let h = match bandwidth {
+            Bandwidth::Fixed(h) => {
+                if !(h >= F::zero()) || h.is_infinite() {
+                    return Err(Error::InvalidBandwidth);
+                }
+                h
+            }
+            Bandwidth::Scott => F::from(1.06).unwrap() * self.std_dev() * n_factor,
+            Bandwidth::Silverman => {
+                let std_dev = self.std_dev();
+                let iqr =
+                    self.quantile(F::from(0.75).unwrap()) - self.quantile(F::from(0.25).unwrap());
+                let spread = if iqr > F::zero() {
+                    std_dev.min(iqr / F::from(1.34).unwrap())
+                } else {
+                    std_dev
+                };
+                F::from(0.9).unwrap() * spread * n_factor
+            }
+        };
+        Ok(Empirical {
+            smoothing: Smoothing::Kernel(kernel, h),
+            ..self
+        })
+    }
+
+    /// Returns the observations in ascending order.
+    pub fn samples(&self) -> &[F] {
+        &self.samples
+    }
+
+    /// Returns the kernel and bandwidth if sampling from a kernel density
+    /// estimate.
+    pub fn kernel(&self) -> Option<(Kernel, F)> {
+        match self.smoothing {
+            Smoothing::Kernel(kernel, h) => Some((kernel, h)),
+            _ => None,
+        }
+    }
+
+    /// Returns the sample quantile for probability `p`, linearly
+    /// interpolating between observations.
+    ///
+    /// For `n` sorted observations `x[0], ..., x[n - 1]`, this is
+    /// `x[i] + (h - i) * (x[i + 1] - x[i])` with `h = (n - 1) p` and
+    /// `i = ⌊h⌋` (the default of R and NumPy). Returns `nan` if `p` is not in
+    /// `[0, 1]`.
+    ///
+    /// The quantile is that of the observations, independent of the
+    /// smoothing chosen for sampling.
+    pub fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        let last = self.samples.len() - 1;
+        let h = F::from(last).unwrap() * p;
+        let i = h.floor();
+        // `last` may be rounded up in `F`
+        let index = i.to_usize().unwrap().min(last);
+        let lower = self.samples[index];
+        match self.samples.get(index + 1) {
+            Some(&upper) => lower + (h - i) * (upper - lower),
+            None => lower,
+        }
+    }
+
+    /// Sample standard deviation, using Bessel's correction.
+    fn std_dev(&self) -> F {
+        if self.samples.len() < 2 {
+            return F::zero();
+        }
+        let n = F::from(self.samples.len()).unwrap();
+        let mean = self.samples.iter().fold(F::zero(), |acc, &x| acc + x) / n;
+        let sum_sq = self
+            .samples
+            .iter()
+            .fold(F::zero(), |acc, &x| acc + (x - mean) * (x - mean));
+        (sum_sq / (n - F::one())).sqrt()
+    }
+}
+
+impl<F> Distribution<F> for Empirical<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        match self.smoothing {
+            Smoothing::None => self.samples[rng.random_range(0..self.samples.len())],
+            Smoothing::Interpolated => {
+                let p: F = rng.sample(StandardUniform);
+                self.quantile(p)
+            }
+            Smoothing::Kernel(kernel, h) => {
+                let x = self.samples[rng.random_range(0..self.samples.len())];
+                let noise: F = match kernel {
+                    Kernel::Gaussian => rng.sample(StandardNormal),
+                    Kernel::Epanechnikov => {
+                        // Devroye (1986), p. 236: the median of three
+                        // uniform samples on [-1, 1] in absolute value
+                        let two = F::from(2.0).unwrap();
+                        let mut u = || two * rng.sample(StandardUniform) - F::one();
+                        let (u1, u2, u3) = (u(), u(), u());
+                        let y = if u3.abs() >= u2.abs() && u3.abs() >= u1.abs() {
+                            u2
+                        } else {
+                            u3
+                        };
+                        y * F::from(5.0).unwrap().sqrt()
+                    }
+                };
+                x + h * noise
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Empirical::<f64>::new(vec![]), Err(Error::NoSamples));
+        assert_eq!(
+            Empirical::new(vec![1.0, f64::NAN]),
+            Err(Error::SampleNotFinite)
+        );
+        let d = Empirical::new(vec![1.0, 2.0]).unwrap();
+        assert_eq!(
+            d.clone()
+                .with_kernel(Kernel::Gaussian, Bandwidth::Fixed(-1.0)),
+            Err(Error::InvalidBandwidth)
+        );
+        assert_eq!(
+            d.with_kernel(Kernel::Epanechnikov, Bandwidth::Fixed(f64::NAN)),
+            Err(Error::InvalidBandwidth)
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        let d = Empirical::new(vec![4.0, 1.0, 3.0, 2.0]).unwrap();
+        assert_eq!(d.samples(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(d.quantile(0.0), 1.0);
+        assert_eq!(d.quantile(0.5), 2.5);
+        assert_eq!(d.quantile(0.25), 1.75);
+        assert_eq!(d.quantile(1.0), 4.0);
+        assert!(d.quantile(1.5).is_nan());
+        assert!(d.quantile(f64::NAN).is_nan());
+
+        let d = Empirical::new(vec![7.0f32]).unwrap();
+        assert_eq!(d.quantile(0.3), 7.0);
+
+        // n - 1 = 2^24 + 3 rounds up to 2^24 + 4 in f32
+        let mut data = vec![0.0f32; (1 << 24) + 4];
+        *data.last_mut().unwrap() = 1.0;
+        let d = Empirical::new(data).unwrap();
+        assert_eq!(d.quantile(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let d = Empirical::new(data).unwrap();
+        let std_dev = (55f64 / 6.0).sqrt();
+        let n_factor = 10f64.powf(-0.2);
+        let scott = d.clone().with_kernel(Kernel::Gaussian, Bandwidth::Scott);
+        let (_, h) = scott.unwrap().kernel().unwrap();
+        assert_almost_eq!(h, 1.06 * std_dev * n_factor, 1e-14);
+        // IQR = 4.5 and IQR / 1.34 > σ
+        let silverman = d
+            .clone()
+            .with_kernel(Kernel::Gaussian, Bandwidth::Silverman);
+        let (_, h) = silverman.unwrap().kernel().unwrap();
+        assert_almost_eq!(h, 0.9 * std_dev * n_factor, 1e-14);
+        assert_eq!(d.kernel(), None);
+
+        // Zero IQR falls back to σ
+        let d = Empirical::new(vec![0.0, 1.0, 1.0, 1.0, 1.0, 2.0]).unwrap();
+        let silverman = d.with_kernel(Kernel::Epanechnikov, Bandwidth::Silverman);
+        let (kernel, h) = silverman.unwrap().kernel().unwrap();
+        assert_eq!(kernel, Kernel::Epanechnikov);
+        assert_almost_eq!(h, 0.9 * 0.4f64.sqrt() * 6f64.powf(-0.2), 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        let data = vec![-3.0, 0.5, 0.5, 2.0, 8.0];
+        let d = Empirical::new(data.clone()).unwrap();
+        let interpolated = d.clone().interpolated();
+        let kde = d
+            .clone()
+            .with_kernel(Kernel::Epanechnikov, Bandwidth::Fixed(0.1))
+            .unwrap();
+        let mut rng = crate::test::rng(361);
+        for _ in 0..1000 {
+            assert!(data.contains(&d.sample(&mut rng)));
+            assert!((-3.0..=8.0).contains(&interpolated.sample(&mut rng)));
+            let x = kde.sample(&mut rng);
+            let bound = 0.1 * 5f64.sqrt();
+            assert!(data.iter().any(|y| (x - y).abs() <= bound));
+        }
+
+        // A bandwidth of zero resamples the observations
+        let kde = d
+            .with_kernel(Kernel::Gaussian, Bandwidth::Fixed(0.0))
+            .unwrap();
+        for _ in 0..100 {
+            assert!(data.contains(&kde.sample(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn empirical_distributions_can_be_compared() {
+        assert_eq!(
+            Empirical::new(vec![1.0, 2.0]),
+            Empirical::new(vec![2.0, 1.0])
+        );
+    }
+}
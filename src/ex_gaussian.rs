@@ -0,0 +1,153 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The exponentially modified Gaussian distribution `ExGaussian(μ, σ, λ)`.
+
+use crate::{Distribution, Exp, Exp1, Normal, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The [exponentially modified Gaussian distribution](https://en.wikipedia.org/wiki/Exponentially_modified_Gaussian_distribution) `ExGaussian(μ, σ, λ)`.
+///
+/// Also known as the ex-Gaussian distribution, this is the distribution of
+/// the sum of independent [`Normal`] `N(μ, σ²)` and [`Exp`] `Exp(λ)` random
+/// variables. It is commonly used to model reaction times and
+/// chromatographic peak shapes.
+///
+/// # Density function
+///
+/// `f(x) = (λ / 2) * exp((λ / 2) (2μ + λσ² - 2x)) * erfc((μ + λσ² - x) / (sqrt(2) σ))`
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{ExGaussian, Distribution};
+///
+/// let ex_gaussian = ExGaussian::new(300.0, 40.0, 0.01).unwrap();
+/// let v = ex_gaussian.sample(&mut rand::rng());
+/// println!("{} is from an ex-Gaussian(300, 40, 0.01) distribution", v)
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    normal: Normal<F>,
+    exp: Exp<F>,
+}
+
+/// Error type returned from [`ExGaussian::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `mu` is infinite or `nan`.
+    LocationNotFinite,
+    /// `sigma <= 0`, infinite or `nan`.
+    ScaleNotPositive,
+    /// `lambda <= 0`, infinite or `nan`.
+    RateNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LocationNotFinite => "mu is not finite in ex-Gaussian distribution",
+            Error::ScaleNotPositive => {
+                "sigma is not positive and finite in ex-Gaussian distribution"
+            }
+            Error::RateNotPositive => {
+                "lambda is not positive and finite in ex-Gaussian distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> ExGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    /// Construct, from the mean `mu` (`μ`) and standard deviation `sigma`
+    /// (`σ`) of the normal component and the rate `lambda` (`λ`) of the
+    /// exponential component.
+    ///
+    /// Parameters:
+    ///
+    /// -   `mu` (must be finite)
+    /// -   `sigma` (must be positive and finite)
+    /// -   `lambda` (must be positive and finite)
+    pub fn new(mu: F, sigma: F, lambda: F) -> Result<ExGaussian<F>, Error> {
+        if !mu.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !(sigma > F::zero()) || sigma.is_infinite() {
+            return Err(Error::ScaleNotPositive);
+        }
+        if !(lambda > F::zero()) || lambda.is_infinite() {
+            return Err(Error::RateNotPositive);
+        }
+        Ok(ExGaussian {
+            normal: Normal::new(mu, sigma).map_err(|_| Error::ScaleNotPositive)?,
+            exp: Exp::new(lambda).map_err(|_| Error::RateNotPositive)?,
+        })
+    }
+}
+
+impl<F> Distribution<F> for ExGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.normal.sample(rng) + self.exp.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ex_gaussian() {
+        let d = ExGaussian::new(300.0, 40.0, 0.01).unwrap();
+        let mut rng = crate::test::rng(251);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_ex_gaussian_invalid() {
+        assert_eq!(
+            ExGaussian::new(f64::NAN, 1.0, 1.0),
+            Err(Error::LocationNotFinite)
+        );
+        assert_eq!(ExGaussian::new(0.0, 0.0, 1.0), Err(Error::ScaleNotPositive));
+        assert_eq!(
+            ExGaussian::new(0.0, 1.0, f64::INFINITY),
+            Err(Error::RateNotPositive)
+        );
+        assert_eq!(ExGaussian::new(0.0, 1.0, -1.0), Err(Error::RateNotPositive));
+    }
+
+    #[test]
+    fn ex_gaussian_distributions_can_be_compared() {
+        assert_eq!(
+            ExGaussian::new(1.0, 2.0, 3.0),
+            ExGaussian::new(1.0, 2.0, 3.0)
+        );
+    }
+}
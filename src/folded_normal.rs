@@ -0,0 +1,220 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The folded normal and half-normal distributions.
+
+use crate::{Distribution, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [half-normal distribution](https://en.wikipedia.org/wiki/Half-normal_distribution) `HalfNormal(σ)`.
+///
+/// This is the distribution of `|X|` where `X ~ N(0, σ²)`. It is a common
+/// weakly informative prior for scale parameters.
+///
+/// See [`FoldedNormal`] for the generalisation to a non-zero mean.
+///
+/// # Density function
+///
+/// `f(x) = (sqrt(2) / (σ sqrt(π))) * exp(-x² / (2σ²))` for `x >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{HalfNormal, Distribution};
+///
+/// let half_normal = HalfNormal::new(2.0).unwrap();
+/// let v = half_normal.sample(&mut rand::rng());
+/// println!("{} is from a HalfNormal(2) distribution", v)
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalfNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    scale: F,
+}
+
+/// Error type returned from [`HalfNormal::new`] and [`FoldedNormal::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The location is infinite or `nan`.
+    LocationNotFinite,
+    /// The scale is not positive and finite.
+    ScaleNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LocationNotFinite => {
+                "location is not finite in (half/folded) normal distribution"
+            }
+            Error::ScaleNotPositive => {
+                "scale is not positive and finite in (half/folded) normal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> HalfNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct, from the scale `σ` (the standard deviation of the
+    /// underlying normal distribution, must be positive and finite).
+    #[inline]
+    pub fn new(scale: F) -> Result<HalfNormal<F>, Error> {
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(Error::ScaleNotPositive);
+        }
+        Ok(HalfNormal { scale })
+    }
+
+    /// Returns the scale (`σ`) of the distribution.
+    pub fn scale(&self) -> F {
+        self.scale
+    }
+}
+
+impl<F> Distribution<F> for HalfNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let z: F = rng.sample(StandardNormal);
+        self.scale * z.abs()
+    }
+}
+
+/// The [folded normal distribution](https://en.wikipedia.org/wiki/Folded_normal_distribution) `FN(μ, σ²)`.
+///
+/// This is the distribution of `|X|` where `X ~ N(μ, σ²)`.
+///
+/// For `μ = 0`, this is the [`HalfNormal`] distribution.
+///
+/// # Density function
+///
+/// `f(x) = (1 / sqrt(2π σ²)) * (exp(-(x - μ)² / (2σ²)) + exp(-(x + μ)² / (2σ²)))`
+/// for `x >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{FoldedNormal, Distribution};
+///
+/// let folded = FoldedNormal::new(1.0, 2.0).unwrap();
+/// let v = folded.sample(&mut rand::rng());
+/// println!("{} is from a folded N(1, 4) distribution", v)
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FoldedNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    location: F,
+    scale: F,
+}
+
+impl<F> FoldedNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct, from the location `μ` and scale `σ` of the underlying
+    /// normal distribution.
+    ///
+    /// Parameters:
+    ///
+    /// -   location (`μ`, must be finite)
+    /// -   scale (`σ`, must be positive and finite)
+    #[inline]
+    pub fn new(location: F, scale: F) -> Result<FoldedNormal<F>, Error> {
+        if !location.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(Error::ScaleNotPositive);
+        }
+        Ok(FoldedNormal { location, scale })
+    }
+
+    /// Returns the location (`μ`) of the underlying normal distribution.
+    pub fn location(&self) -> F {
+        self.location
+    }
+
+    /// Returns the scale (`σ`) of the underlying normal distribution.
+    pub fn scale(&self) -> F {
+        self.scale
+    }
+}
+
+impl<F> Distribution<F> for FoldedNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let z: F = rng.sample(StandardNormal);
+        (self.location + self.scale * z).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_normal() {
+        let d = HalfNormal::new(2.0).unwrap();
+        assert_eq!(d.scale(), 2.0);
+        let mut rng = crate::test::rng(212);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_folded_normal() {
+        let d = FoldedNormal::new(-1.0, 2.0).unwrap();
+        assert_eq!((d.location(), d.scale()), (-1.0, 2.0));
+        let mut rng = crate::test::rng(213);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(HalfNormal::new(0.0), Err(Error::ScaleNotPositive));
+        assert_eq!(HalfNormal::new(f64::NAN), Err(Error::ScaleNotPositive));
+        assert_eq!(HalfNormal::new(f64::INFINITY), Err(Error::ScaleNotPositive));
+        assert_eq!(
+            FoldedNormal::new(f64::INFINITY, 1.0),
+            Err(Error::LocationNotFinite)
+        );
+        assert_eq!(FoldedNormal::new(0.0, -1.0), Err(Error::ScaleNotPositive));
+    }
+
+    #[test]
+    fn folded_normal_distributions_can_be_compared() {
+        assert_eq!(HalfNormal::new(1.0), HalfNormal::new(1.0));
+        assert_eq!(FoldedNormal::new(1.0, 2.0), FoldedNormal::new(1.0, 2.0));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generalized Poisson distribution `GeneralizedPoisson(θ, λ)`.
+
+use crate::{Distribution, Poisson};
+use core::fmt;
+use rand::Rng;
+
+/// The generalized Poisson distribution `GeneralizedPoisson(θ, λ)` of
+/// Consul and Jain.
+///
+/// This over-dispersed generalization of the [`Poisson`] distribution has
+/// mean `θ / (1 - λ)` and variance `θ / (1 - λ)³`. For `λ = 0` it is
+/// `Poisson(θ)`. See [`ConwayMaxwellPoisson`](crate::ConwayMaxwellPoisson)
+/// for under-dispersed counts.
+///
+/// # Density function
+///
+/// `f(k) = θ (θ + λk)^(k - 1) exp(-θ - λk) / k!` for `k >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, GeneralizedPoisson};
+///
+/// let gp = GeneralizedPoisson::new(3.0, 0.4).unwrap();
+/// let v = gp.sample(&mut rand::rng());
+/// println!("{} is from a generalized Poisson distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// The distribution is that of the total progeny of a branching process
+/// with `Poisson(θ)` initial individuals, each of which has `Poisson(λ)`
+/// offspring. Samples are generated by simulating this process one
+/// generation at a time, at a cost of one [`Poisson`] sample per
+/// generation. The number of generations grows as `λ` approaches 1.
+///
+/// When a generation exceeds [`Poisson::MAX_LAMBDA`] individuals, which
+/// can only happen for `θ` close to this limit, the sample saturates to
+/// `u64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralizedPoisson {
+    lambda: f64,
+    poisson: Poisson<f64>,
+}
+
+/// Error type returned from [`GeneralizedPoisson::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `theta` is not a valid [`Poisson`] rate.
+    ThetaInvalid,
+    /// `lambda < 0`, `lambda >= 1` or `nan`.
+    LambdaInvalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ThetaInvalid => {
+                "theta is not a valid Poisson rate in generalized Poisson distribution"
+            }
+            Error::LambdaInvalid => {
+                "lambda is NaN or outside the interval [0, 1) in generalized Poisson distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl GeneralizedPoisson {
+    /// Construct a new `GeneralizedPoisson` distribution with rate parameter
+    /// `theta` and dispersion parameter `lambda`.
+    ///
+    /// The constraints on `theta` are those of [`Poisson::new`].
+    pub fn new(theta: f64, lambda: f64) -> Result<GeneralizedPoisson, Error> {
+        if !(0.0..1.0).contains(&lambda) {
+            return Err(Error::LambdaInvalid);
+        }
+        Ok(GeneralizedPoisson {
+            lambda,
+            poisson: Poisson::new(theta).map_err(|_| Error::ThetaInvalid)?,
+        })
+    }
+
+    /// Returns the rate parameter `theta`.
+    pub fn theta(&self) -> f64 {
+        self.poisson.lambda()
+    }
+
+    /// Returns the dispersion parameter `lambda`.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Distribution<u64> for GeneralizedPoisson {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let mut generation: f64 = self.poisson.sample(rng);
+        let mut total = generation as u64;
+        while generation > 0.0 && self.lambda > 0.0 {
+            generation = match Poisson::new(self.lambda * generation) {
+                Ok(poisson) => poisson.sample(rng),
+                Err(_) => return u64::MAX,
+            };
+            total = total.saturating_add(generation as u64);
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        for theta in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                GeneralizedPoisson::new(theta, 0.5),
+                Err(Error::ThetaInvalid)
+            );
+        }
+        for lambda in [-0.1, 1.0, f64::NAN] {
+            assert_eq!(
+                GeneralizedPoisson::new(1.0, lambda),
+                Err(Error::LambdaInvalid)
+            );
+        }
+        let gp = GeneralizedPoisson::new(2.0, 0.0).unwrap();
+        assert_eq!((gp.theta(), gp.lambda()), (2.0, 0.0));
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(473);
+        for (theta, lambda) in [(5.0, 0.0), (2.0, 0.3), (10.0, 0.6)] {
+            let d = GeneralizedPoisson::new(theta, lambda).unwrap();
+            let expected_mean = theta / (1.0 - lambda);
+            let expected_variance = expected_mean / ((1.0 - lambda) * (1.0 - lambda));
+
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < expected_mean / 20.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+        }
+    }
+
+    #[test]
+    fn generalized_poisson_distributions_can_be_compared() {
+        assert_eq!(
+            GeneralizedPoisson::new(1.0, 0.5),
+            GeneralizedPoisson::new(1.0, 0.5)
+        );
+    }
+}
@@ -1,8 +1,7 @@
 //! The geometric distribution `Geometric(p)`.
 
-use crate::Distribution;
+use crate::{Distribution, StandardUniform};
 use core::fmt;
-#[allow(unused_imports)]
 use num_traits::Float;
 use rand::{Rng, RngExt};
 
@@ -38,11 +37,28 @@ use rand::{Rng, RngExt};
 /// let v = geo.sample(&mut rand::rng());
 /// println!("{} is from a Geometric(0.25) distribution", v);
 /// ```
+///
+/// # Float type
+///
+/// The probability `p` and the internal computations use the float type
+/// `F`, which defaults to `f64`. Using `f32` avoids `f64` arithmetic on
+/// targets without hardware support for it:
+///
+/// ```
+/// use rand_distr::{Distribution, Geometric};
+///
+/// let geo = Geometric::new(0.25f32).unwrap();
+/// let v = geo.sample(&mut rand::rng());
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Geometric {
-    p: f64,
-    pi: f64,
+pub struct Geometric<F = f64>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    p: F,
+    pi: F,
     k: u64,
 }
 
@@ -66,7 +82,11 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
-impl Geometric {
+impl<F> Geometric<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
     /// Construct a new `Geometric` distribution
     ///
     /// The shape parameter `p` is the probability of success on each trial.
@@ -75,18 +95,18 @@ impl Geometric {
     ///
     /// If `p == 0.0` or `1.0 - p` rounds to `1.0` then sampling returns
     /// `u64::MAX`.
-    pub fn new(p: f64) -> Result<Self, Error> {
-        let mut pi = 1.0 - p;
-        if !p.is_finite() || !(0.0..=1.0).contains(&p) {
+    pub fn new(p: F) -> Result<Self, Error> {
+        let mut pi = F::one() - p;
+        if !p.is_finite() || !(F::zero()..=F::one()).contains(&p) {
             Err(Error::InvalidProbability)
-        } else if pi == 1.0 || p >= 2.0 / 3.0 {
+        } else if pi == F::one() || p >= F::from(2.0 / 3.0).unwrap() {
             Ok(Geometric { p, pi, k: 0 })
         } else {
             let (pi, k) = {
                 // choose smallest k such that pi = (1 - p)^(2^k) <= 0.5
                 let mut k = 1;
                 pi = pi * pi;
-                while pi > 0.5 {
+                while pi > F::from(0.5).unwrap() {
                     k += 1;
                     pi = pi * pi;
                 }
@@ -96,15 +116,24 @@ impl Geometric {
             Ok(Geometric { p, pi, k })
         }
     }
+
+    /// Returns the probability of success `p`.
+    pub fn p(&self) -> F {
+        self.p
+    }
 }
 
-impl Distribution<u64> for Geometric {
+impl<F> Distribution<u64> for Geometric<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
-        if self.p >= 2.0 / 3.0 {
+        if self.p >= F::from(2.0 / 3.0).unwrap() {
             // use the trivial algorithm:
             let mut failures = 0;
             loop {
-                let u = rng.random::<f64>();
+                let u = rng.random::<F>();
                 if u <= self.p {
                     break;
                 }
@@ -113,7 +142,7 @@ impl Distribution<u64> for Geometric {
             return failures;
         }
 
-        if self.pi == 1.0 {
+        if self.pi == F::one() {
             return u64::MAX;
         }
 
@@ -129,7 +158,7 @@ impl Distribution<u64> for Geometric {
         // Use the trivial algorithm to sample D from Geo(pi) = Geo(p) / 2^k:
         let d = {
             let mut failures = 0;
-            while rng.random::<f64>() < pi {
+            while rng.random::<F>() < pi {
                 failures += 1;
             }
             failures
@@ -143,12 +172,12 @@ impl Distribution<u64> for Geometric {
         let m = loop {
             let m = rng.random::<u64>() & ((1 << k) - 1);
             let p_reject = if m <= i32::MAX as u64 {
-                (1.0 - p).powi(m as i32)
+                (F::one() - p).powi(m as i32)
             } else {
-                (1.0 - p).powf(m as f64)
+                (F::one() - p).powf(F::from(m).unwrap())
             };
 
-            let u = rng.random::<f64>();
+            let u = rng.random::<F>();
             if u < p_reject {
                 break m;
             }
@@ -216,8 +245,14 @@ mod test {
         assert!(Geometric::new(2.0).is_err());
     }
 
-    fn test_geo_mean_and_variance<R: Rng>(p: f64, rng: &mut R) {
+    fn test_geo_mean_and_variance<F, R>(p: F, rng: &mut R)
+    where
+        F: Float,
+        StandardUniform: Distribution<F>,
+        R: Rng,
+    {
         let distr = Geometric::new(p).unwrap();
+        let p = p.to_f64().unwrap();
 
         let expected_mean = (1.0 - p) / p;
         let expected_variance = (1.0 - p) / (p * p);
@@ -246,6 +281,15 @@ mod test {
         test_geo_mean_and_variance(0.90, &mut rng);
     }
 
+    #[test]
+    fn test_geometric_f32() {
+        let mut rng = crate::test::rng(12346);
+
+        test_geo_mean_and_variance(0.10f32, &mut rng);
+        test_geo_mean_and_variance(0.50f32, &mut rng);
+        test_geo_mean_and_variance(0.90f32, &mut rng);
+    }
+
     #[test]
     fn test_standard_geometric() {
         let mut rng = crate::test::rng(654321);
@@ -0,0 +1,258 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Gompertz and Gompertz–Makeham distributions.
+
+use crate::{Distribution, Exp, Exp1};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Gompertz distribution](https://en.wikipedia.org/wiki/Gompertz_distribution) `Gompertz(b, η)`.
+///
+/// This is a continuous probability distribution with scale parameter `b`
+/// and shape parameter `η` (`eta`). Its hazard rate `bη exp(bx)` grows
+/// exponentially with `x`, which makes it the classical model of adult
+/// human mortality. See [`GompertzMakeham`] for the variant with an
+/// additional age-independent hazard.
+///
+/// # Density function
+///
+/// `f(x; b, η) = bη * exp(η + bx - η * exp(bx))` for `x >= 0`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::Gompertz;
+///
+/// let val: f64 = rand::rng().sample(Gompertz::new(0.1, 0.01).unwrap());
+/// println!("{}", val);
+/// ```
+///
+/// # Notes
+///
+/// Sampling uses inversion of the distribution function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gompertz<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    inv_scale: F,
+    inv_shape: F,
+}
+
+/// Error type returned from [`Gompertz::new`] and [`GompertzMakeham::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+    /// `shape <= 0` or `nan`.
+    ShapeTooSmall,
+    /// `makeham < 0` or `nan` (Gompertz–Makeham only).
+    MakehamTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ScaleTooSmall => "scale is not positive in Gompertz distribution",
+            Error::ShapeTooSmall => "shape is not positive in Gompertz distribution",
+            Error::MakehamTooSmall => {
+                "Makeham term is negative or NaN in Gompertz-Makeham distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Gompertz<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    /// Construct a new `Gompertz` distribution with given `scale` and `shape`.
+    ///
+    /// In the literature, `scale` is commonly written as `b` and `shape` as
+    /// `η`. The hazard rate at age `x` is `scale * shape * exp(scale * x)`.
+    pub fn new(scale: F, shape: F) -> Result<Gompertz<F>, Error> {
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        if !(shape > F::zero()) {
+            return Err(Error::ShapeTooSmall);
+        }
+        Ok(Gompertz {
+            inv_scale: F::one() / scale,
+            inv_shape: F::one() / shape,
+        })
+    }
+}
+
+impl<F> Distribution<F> for Gompertz<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // Inverting F(x) = 1 - exp(-η (exp(bx) - 1)) with E = -ln(1 - U):
+        let e: F = rng.sample(Exp1);
+        (e * self.inv_shape).ln_1p() * self.inv_scale
+    }
+}
+
+/// The [Gompertz–Makeham distribution](https://en.wikipedia.org/wiki/Gompertz%E2%80%93Makeham_law_of_mortality) `GM(λ, b, η)`.
+///
+/// This extends the [`Gompertz`] distribution with a constant,
+/// age-independent hazard `λ` (`makeham`), giving the hazard rate
+/// `λ + bη exp(bx)`. Samples are the minimum of a `Gompertz(b, η)` and an
+/// [`Exp(λ)`](crate::Exp) lifetime.
+///
+/// For `λ = 0` this is the [`Gompertz`] distribution.
+///
+/// # Density function
+///
+/// `f(x; λ, b, η) = (λ + bη exp(bx)) * exp(-λx - η (exp(bx) - 1))` for `x >= 0`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::GompertzMakeham;
+///
+/// let val: f64 = rand::rng().sample(GompertzMakeham::new(0.002, 0.1, 0.01).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GompertzMakeham<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    gompertz: Gompertz<F>,
+    exp: Exp<F>,
+}
+
+impl<F> GompertzMakeham<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    /// Construct a new `GompertzMakeham` distribution with given Makeham
+    /// term `makeham` (`λ >= 0`), and Gompertz `scale` (`b > 0`) and `shape`
+    /// (`η > 0`).
+    pub fn new(makeham: F, scale: F, shape: F) -> Result<GompertzMakeham<F>, Error> {
+        if !(makeham >= F::zero()) {
+            return Err(Error::MakehamTooSmall);
+        }
+        let gompertz = Gompertz::new(scale, shape)?;
+        let exp = Exp::new(makeham).map_err(|_| Error::MakehamTooSmall)?;
+        Ok(GompertzMakeham { gompertz, exp })
+    }
+}
+
+impl<F> Distribution<F> for GompertzMakeham<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let x = self.gompertz.sample(rng);
+        let y = self.exp.sample(rng);
+        x.min(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Gompertz::new(0., 1.), Err(Error::ScaleTooSmall));
+        assert_eq!(Gompertz::new(1., f64::NAN), Err(Error::ShapeTooSmall));
+        assert_eq!(
+            GompertzMakeham::new(-1., 1., 1.),
+            Err(Error::MakehamTooSmall)
+        );
+        assert_eq!(
+            GompertzMakeham::new(-0.0, 1., 1.),
+            Err(Error::MakehamTooSmall)
+        );
+        assert_eq!(GompertzMakeham::new(0., 1., 0.), Err(Error::ShapeTooSmall));
+    }
+
+    #[test]
+    fn sample() {
+        let d = Gompertz::new(0.1, 0.01).unwrap();
+        let gm = GompertzMakeham::new(0.05, 0.1, 0.01).unwrap();
+        let mut rng = crate::test::rng(1);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.);
+            assert!(gm.sample(&mut rng) >= 0.);
+        }
+    }
+
+    #[test]
+    fn makeham_zero_is_gompertz() {
+        let d = Gompertz::new(0.5, 2.0).unwrap();
+        let gm = GompertzMakeham::new(0.0, 0.5, 2.0).unwrap();
+        let mut rng1 = crate::test::rng(2);
+        let mut rng2 = crate::test::rng(2);
+        for _ in 0..100 {
+            let x: f64 = d.sample(&mut rng1);
+            assert_eq!(gm.sample(&mut rng2), x);
+            // GompertzMakeham draws an additional exponential sample.
+            let _: f64 = rng1.sample(Exp1);
+        }
+    }
+
+    #[test]
+    fn value_stability() {
+        fn test_samples<F: Float + fmt::Debug, D: Distribution<F>>(
+            distr: D,
+            zero: F,
+            expected: &[F],
+        ) {
+            let mut rng = crate::test::rng(213);
+            let mut buf = [zero; 4];
+            for x in &mut buf {
+                *x = rng.sample(&distr);
+            }
+            assert_eq!(buf, expected);
+        }
+
+        test_samples(
+            Gompertz::new(1.0, 1.0).unwrap(),
+            0f32,
+            &[0.82261217, 1.1124802, 0.26922935, 0.22979224],
+        );
+        test_samples(
+            GompertzMakeham::new(0.5, 2.0, 0.5).unwrap(),
+            0f64,
+            &[
+                0.6338788634275038,
+                0.2405678043496482,
+                0.941595506129478,
+                0.5878734131426859,
+            ],
+        );
+    }
+
+    #[test]
+    fn gompertz_distributions_can_be_compared() {
+        assert_eq!(Gompertz::new(1.0, 2.0), Gompertz::new(1.0, 2.0));
+        assert_eq!(
+            GompertzMakeham::new(0.5, 1.0, 2.0),
+            GompertzMakeham::new(0.5, 1.0, 2.0)
+        );
+    }
+}
@@ -1,29 +1,27 @@
 //! The hypergeometric distribution `Hypergeometric(N, K, n)`.
 
-use crate::Distribution;
+use crate::{Distribution, StandardUniform, Uniform, uniform::SampleUniform};
 use core::fmt;
-#[allow(unused_imports)]
-use num_traits::Float;
-use rand::distr::uniform::Uniform;
+use num_traits::{Float, ToPrimitive};
 use rand::{Rng, RngExt};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-enum SamplingMethod {
+enum SamplingMethod<F> {
     InverseTransform {
-        initial_p: f64,
+        initial_p: F,
         initial_x: i64,
     },
     RejectionAcceptance {
-        m: f64,
-        a: f64,
-        lambda_l: f64,
-        lambda_r: f64,
-        x_l: f64,
-        x_r: f64,
-        p1: f64,
-        p2: f64,
-        p3: f64,
+        m: F,
+        a: F,
+        lambda_l: F,
+        lambda_r: F,
+        x_l: F,
+        x_r: F,
+        p1: F,
+        p2: F,
+        p3: F,
     },
 }
 
@@ -57,21 +55,46 @@ enum SamplingMethod {
 /// let v = hypergeo.sample(&mut rand::rng());
 /// println!("{} is from a hypergeometric distribution", v);
 /// ```
+///
+/// # Float type
+///
+/// The internal computations use the float type `F`, which defaults to
+/// `f64`. Unlike for [`Binomial::new`](crate::Binomial::new) and
+/// [`Geometric::new`](crate::Geometric::new), the float type cannot be
+/// inferred from the parameters, which are integers. Therefore
+/// [`Hypergeometric::new`] always uses `f64`, and other float types are
+/// selected with [`Hypergeometric::new_with_float`]. Using `f32` avoids
+/// `f64` arithmetic on targets without hardware support for it, but
+/// supports smaller populations:
+///
+/// ```
+/// use rand_distr::{Distribution, Hypergeometric};
+///
+/// let hypergeo = Hypergeometric::<f32>::new_with_float(60, 24, 7).unwrap();
+/// let v = hypergeo.sample(&mut rand::rng());
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Hypergeometric {
+pub struct Hypergeometric<F = f64>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
     n1: u64,
     n2: u64,
     k: u64,
     offset_x: i64,
     sign_x: i64,
-    sampling_method: SamplingMethod,
+    sampling_method: SamplingMethod<F>,
 }
 
 /// Error type returned from [`Hypergeometric::new`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Error {
-    /// `total_population_size` is too large, causing floating point underflow.
+    /// `total_population_size` is too large for the float type, causing
+    /// floating point underflow or overflow, or too much rounding error in
+    /// the density. For `f64`, this is the case for populations of about
+    /// `10^14` and more.
     PopulationTooLarge,
     /// `population_with_feature > total_population_size`.
     ProbabilityTooLarge,
@@ -98,8 +121,16 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+// convert a number to the float type `F`
+fn float<F: Float, T: ToPrimitive>(x: T) -> F {
+    F::from(x).unwrap()
+}
+
 // evaluate fact(numerator.0)*fact(numerator.1) / fact(denominator.0)*fact(denominator.1)
-fn fraction_of_products_of_factorials(numerator: (u64, u64), denominator: (u64, u64)) -> f64 {
+fn fraction_of_products_of_factorials<F: Float>(
+    numerator: (u64, u64),
+    denominator: (u64, u64),
+) -> F {
     let min_top = u64::min(numerator.0, numerator.1);
     let min_bottom = u64::min(denominator.0, denominator.1);
     // the factorial of this will cancel out:
@@ -109,22 +140,22 @@ fn fraction_of_products_of_factorials(numerator: (u64, u64), denominator: (u64,
     let max_bottom = u64::max(denominator.0, denominator.1);
     let max_all = u64::max(max_top, max_bottom);
 
-    let mut result = 1.0;
+    let mut result = F::one();
     for i in (min_all + 1)..=max_all {
         if i <= min_top {
-            result *= i as f64;
+            result = result * float(i);
         }
 
         if i <= min_bottom {
-            result /= i as f64;
+            result = result / float(i);
         }
 
         if i <= max_top {
-            result *= i as f64;
+            result = result * float(i);
         }
 
         if i <= max_bottom {
-            result /= i as f64;
+            result = result / float(i);
         }
     }
 
@@ -133,27 +164,50 @@ fn fraction_of_products_of_factorials(numerator: (u64, u64), denominator: (u64,
 
 const LOGSQRT2PI: f64 = 0.91893853320467274178; // log(sqrt(2*pi))
 
-fn ln_of_factorial(v: f64) -> f64 {
+fn ln_of_factorial<F: Float>(v: F) -> F {
     // the paper calls for ln(v!), but also wants to pass in fractions,
     // so we need to use Stirling's approximation to fill in the gaps:
 
     // shift v by 3, because Stirling is bad for small values
-    let v_3 = v + 3.0;
-    let ln_fac = (v_3 + 0.5) * v_3.ln() - v_3 + LOGSQRT2PI + 1.0 / (12.0 * v_3);
+    let v_3 = v + float(3.0);
+    let ln_fac = (v_3 + float(0.5)) * v_3.ln() - v_3
+        + float(LOGSQRT2PI)
+        + F::one() / (float::<F, _>(12.0) * v_3);
     // make the correction for the shift
-    ln_fac - ((v + 3.0) * (v + 2.0) * (v + 1.0)).ln()
+    ln_fac - ((v + float(3.0)) * (v + float(2.0)) * (v + F::one())).ln()
 }
 
-impl Hypergeometric {
+impl Hypergeometric<f64> {
     /// Constructs a new `Hypergeometric` with the shape parameters
     /// `N = total_population_size`,
     /// `K = population_with_feature`,
     /// `n = sample_size`.
-    #[allow(clippy::many_single_char_names)] // Same names as in the reference.
+    ///
+    /// See [`Hypergeometric::new_with_float`] for other float types.
     pub fn new(
         total_population_size: u64,
         population_with_feature: u64,
         sample_size: u64,
+    ) -> Result<Self, Error> {
+        Self::new_with_float(total_population_size, population_with_feature, sample_size)
+    }
+}
+
+impl<F> Hypergeometric<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
+    /// Constructs a new `Hypergeometric` using the float type `F` with the
+    /// shape parameters
+    /// `N = total_population_size`,
+    /// `K = population_with_feature`,
+    /// `n = sample_size`.
+    #[allow(clippy::many_single_char_names)] // Same names as in the reference.
+    pub fn new_with_float(
+        total_population_size: u64,
+        population_with_feature: u64,
+        sample_size: u64,
     ) -> Result<Self, Error> {
         if population_with_feature > total_population_size {
             return Err(Error::ProbabilityTooLarge);
@@ -201,80 +255,94 @@ impl Hypergeometric {
         // J. Statist. Comput. Simul. Vol.22 (August 1985), 127-145
         // https://www.researchgate.net/publication/233212638
         const HIN_THRESHOLD: f64 = 10.0;
-        let m = ((k + 1) as f64 * (n1 + 1) as f64 / (n + 2) as f64).floor();
-        let sampling_method = if m - f64::max(0.0, k as f64 - n2 as f64) < HIN_THRESHOLD {
-            let (initial_p, initial_x) = if k < n2 {
-                (
-                    fraction_of_products_of_factorials((n2, n - k), (n, n2 - k)),
-                    0,
-                )
-            } else {
-                (
-                    fraction_of_products_of_factorials((n1, k), (n, k - n2)),
-                    (k - n2) as i64,
-                )
-            };
+        let m: F = (float::<F, _>(k + 1) * float(n1 + 1) / float(n + 2)).floor();
+        let sampling_method =
+            if m - F::max(F::zero(), float::<F, _>(k) - float(n2)) < float(HIN_THRESHOLD) {
+                let (initial_p, initial_x) = if k < n2 {
+                    (
+                        fraction_of_products_of_factorials::<F>((n2, n - k), (n, n2 - k)),
+                        0,
+                    )
+                } else {
+                    (
+                        fraction_of_products_of_factorials::<F>((n1, k), (n, k - n2)),
+                        (k - n2) as i64,
+                    )
+                };
+
+                if initial_p <= F::zero() || !initial_p.is_finite() {
+                    return Err(Error::PopulationTooLarge);
+                }
 
-            if initial_p <= 0.0 || !initial_p.is_finite() {
-                return Err(Error::PopulationTooLarge);
-            }
+                SamplingMethod::InverseTransform {
+                    initial_p,
+                    initial_x,
+                }
+            } else {
+                let (n1_f, k_f, n2_k_f): (F, F, F) = (float(n1), float(k), float(n2 - k));
+                let a = ln_of_factorial(m)
+                    + ln_of_factorial(n1_f - m)
+                    + ln_of_factorial(k_f - m)
+                    + ln_of_factorial(n2_k_f + m);
+
+                let numerator = float::<F, _>(n - k) * k_f * n1_f * float(n2);
+                let denominator = float::<F, _>(n - 1) * float(n) * float(n);
+                let d = float::<F, _>(1.5) * (numerator / denominator).sqrt() + float(0.5);
+
+                let x_l = m - d + float(0.5);
+                let x_r = m + d + float(0.5);
+
+                let k_l = F::exp(
+                    a - ln_of_factorial(x_l)
+                        - ln_of_factorial(n1_f - x_l)
+                        - ln_of_factorial(k_f - x_l)
+                        - ln_of_factorial(n2_k_f + x_l),
+                );
+                let k_r = F::exp(
+                    a - ln_of_factorial(x_r - F::one())
+                        - ln_of_factorial(n1_f - x_r + F::one())
+                        - ln_of_factorial(k_f - x_r + F::one())
+                        - ln_of_factorial(n2_k_f + x_r - F::one()),
+                );
+
+                let numerator = x_l * (n2_k_f + x_l);
+                let denominator = (n1_f - x_l + F::one()) * (k_f - x_l + F::one());
+                let lambda_l = -((numerator / denominator).ln());
+
+                let numerator = (n1_f - x_r + F::one()) * (k_f - x_r + F::one());
+                let denominator = x_r * (n2_k_f + x_r);
+                let lambda_r = -((numerator / denominator).ln());
+
+                // the paper literally gives `p2 + kL/lambdaL` where it (probably)
+                // should have been `p2 <- p1 + kL/lambdaL`; another print error?!
+                let p1 = float::<F, _>(2.0) * d;
+                let p2 = p1 + k_l / lambda_l;
+                let p3 = p2 + k_r / lambda_r;
+
+                // The density is evaluated as a difference of logarithms of
+                // factorials of the order of `a`. Their rounding errors
+                // distort the density, and make the acceptance test fail
+                // indefinitely once they are large.
+                if a * F::epsilon() > F::one()
+                    || [k_l, k_r, lambda_l, lambda_r, p3]
+                        .iter()
+                        .any(|x| !x.is_finite())
+                {
+                    return Err(Error::PopulationTooLarge);
+                }
 
-            SamplingMethod::InverseTransform {
-                initial_p,
-                initial_x,
-            }
-        } else {
-            let a = ln_of_factorial(m)
-                + ln_of_factorial(n1 as f64 - m)
-                + ln_of_factorial(k as f64 - m)
-                + ln_of_factorial((n2 - k) as f64 + m);
-
-            let numerator = (n - k) as f64 * k as f64 * n1 as f64 * n2 as f64;
-            let denominator = (n - 1) as f64 * n as f64 * n as f64;
-            let d = 1.5 * (numerator / denominator).sqrt() + 0.5;
-
-            let x_l = m - d + 0.5;
-            let x_r = m + d + 0.5;
-
-            let k_l = f64::exp(
-                a - ln_of_factorial(x_l)
-                    - ln_of_factorial(n1 as f64 - x_l)
-                    - ln_of_factorial(k as f64 - x_l)
-                    - ln_of_factorial((n2 - k) as f64 + x_l),
-            );
-            let k_r = f64::exp(
-                a - ln_of_factorial(x_r - 1.0)
-                    - ln_of_factorial(n1 as f64 - x_r + 1.0)
-                    - ln_of_factorial(k as f64 - x_r + 1.0)
-                    - ln_of_factorial((n2 - k) as f64 + x_r - 1.0),
-            );
-
-            let numerator = x_l * ((n2 - k) as f64 + x_l);
-            let denominator = (n1 as f64 - x_l + 1.0) * (k as f64 - x_l + 1.0);
-            let lambda_l = -((numerator / denominator).ln());
-
-            let numerator = (n1 as f64 - x_r + 1.0) * (k as f64 - x_r + 1.0);
-            let denominator = x_r * ((n2 - k) as f64 + x_r);
-            let lambda_r = -((numerator / denominator).ln());
-
-            // the paper literally gives `p2 + kL/lambdaL` where it (probably)
-            // should have been `p2 <- p1 + kL/lambdaL`; another print error?!
-            let p1 = 2.0 * d;
-            let p2 = p1 + k_l / lambda_l;
-            let p3 = p2 + k_r / lambda_r;
-
-            SamplingMethod::RejectionAcceptance {
-                m,
-                a,
-                lambda_l,
-                lambda_r,
-                x_l,
-                x_r,
-                p1,
-                p2,
-                p3,
-            }
-        };
+                SamplingMethod::RejectionAcceptance {
+                    m,
+                    a,
+                    lambda_l,
+                    lambda_r,
+                    x_l,
+                    x_r,
+                    p1,
+                    p2,
+                    p3,
+                }
+            };
 
         Ok(Hypergeometric {
             n1,
@@ -287,7 +355,11 @@ impl Hypergeometric {
     }
 }
 
-impl Distribution<u64> for Hypergeometric {
+impl<F> Distribution<u64> for Hypergeometric<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
     #[allow(clippy::many_single_char_names)] // Same names as in the reference.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
         use SamplingMethod::*;
@@ -305,13 +377,13 @@ impl Distribution<u64> for Hypergeometric {
                 initial_p: mut p,
                 initial_x: mut x,
             } => {
-                let mut u = rng.random::<f64>();
+                let mut u = rng.random::<F>();
 
                 // the paper erroneously uses `until n < p`, which doesn't make any sense
                 while u > p && x < k as i64 {
-                    u -= p;
-                    p *= ((n1 as i64 - x) * (k as i64 - x)) as f64;
-                    p /= ((x + 1) * (n2 as i64 - k as i64 + 1 + x)) as f64;
+                    u = u - p;
+                    p = p * float((n1 as i64 - x) * (k as i64 - x));
+                    p = p / float((x + 1) * (n2 as i64 - k as i64 + 1 + x));
                     x += 1;
                 }
                 x
@@ -327,11 +399,13 @@ impl Distribution<u64> for Hypergeometric {
                 p2,
                 p3,
             } => {
-                let distr_region_select = Uniform::new(0.0, p3).unwrap();
+                let (one, half): (F, F) = (F::one(), float(0.5));
+                let (n1_f, k_f, n2_f): (F, F, F) = (float(n1), float(k), float(n2));
+                let distr_region_select = Uniform::new(F::zero(), p3).unwrap();
                 loop {
                     let (y, v) = loop {
                         let u = distr_region_select.sample(rng);
-                        let v = rng.random::<f64>(); // for the accept/reject decision
+                        let v = rng.random::<F>(); // for the accept/reject decision
 
                         if u <= p1 {
                             // Region 1, central bell
@@ -340,14 +414,14 @@ impl Distribution<u64> for Hypergeometric {
                         } else if u <= p2 {
                             // Region 2, left exponential tail
                             let y = (x_l + v.ln() / lambda_l).floor();
-                            if y as i64 >= i64::max(0, k as i64 - n2 as i64) {
+                            if y >= float(i64::max(0, k as i64 - n2 as i64)) {
                                 let v = v * (u - p1) * lambda_l;
                                 break (y, v);
                             }
                         } else {
                             // Region 3, right exponential tail
                             let y = (x_r - v.ln() / lambda_r).floor();
-                            if y as u64 <= u64::min(n1, k) {
+                            if y <= float(u64::min(n1, k)) {
                                 let v = v * (u - p2) * lambda_r;
                                 break (y, v);
                             }
@@ -355,87 +429,92 @@ impl Distribution<u64> for Hypergeometric {
                     };
 
                     // Step 4: Acceptance/Rejection Comparison
-                    if m < 100.0 || y <= 50.0 {
+                    if m < float(100.0) || y <= float(50.0) {
                         // Step 4.1: evaluate f(y) via recursive relationship
-                        let mut f = 1.0;
+                        let mut f = one;
+                        let (m_u, y_u) = (m.to_u64().unwrap(), y.to_u64().unwrap());
                         if m < y {
-                            for i in (m as u64 + 1)..=(y as u64) {
-                                f *= (n1 - i + 1) as f64 * (k - i + 1) as f64;
-                                f /= i as f64 * (n2 - k + i) as f64;
+                            for i in (m_u + 1)..=y_u {
+                                f = f * (float::<F, _>(n1 - i + 1) * float(k - i + 1));
+                                f = f / (float::<F, _>(i) * float(n2 - k + i));
                             }
                         } else {
-                            for i in (y as u64 + 1)..=(m as u64) {
-                                f *= i as f64 * (n2 - k + i) as f64;
-                                f /= (n1 - i + 1) as f64 * (k - i + 1) as f64;
+                            for i in (y_u + 1)..=m_u {
+                                f = f * (float::<F, _>(i) * float(n2 - k + i));
+                                f = f / (float::<F, _>(n1 - i + 1) * float(k - i + 1));
                             }
                         }
 
                         if v <= f {
-                            break y as i64;
+                            break y.to_i64().unwrap();
                         }
                     } else {
                         // Step 4.2: Squeezing
-                        let y1 = y + 1.0;
+                        let series = |x: F| x * (one + x * (-half + x / float(3.0)));
+                        let y1 = y + one;
                         let ym = y - m;
-                        let yn = n1 as f64 - y + 1.0;
-                        let yk = k as f64 - y + 1.0;
-                        let nk = n2 as f64 - k as f64 + y1;
+                        let yn = n1_f - y + one;
+                        let yk = k_f - y + one;
+                        let nk = n2_f - k_f + y1;
                         let r = -ym / y1;
                         let s = ym / yn;
                         let t = ym / yk;
                         let e = -ym / nk;
-                        let g = yn * yk / (y1 * nk) - 1.0;
-                        let dg = if g < 0.0 { 1.0 + g } else { 1.0 };
-                        let gu = g * (1.0 + g * (-0.5 + g / 3.0));
-                        let gl = gu - g.powi(4) / (4.0 * dg);
-                        let xm = m + 0.5;
-                        let xn = n1 as f64 - m + 0.5;
-                        let xk = k as f64 - m + 0.5;
-                        let nm = n2 as f64 - k as f64 + xm;
-                        let ub = xm * r * (1.0 + r * (-0.5 + r / 3.0))
-                            + xn * s * (1.0 + s * (-0.5 + s / 3.0))
-                            + xk * t * (1.0 + t * (-0.5 + t / 3.0))
-                            + nm * e * (1.0 + e * (-0.5 + e / 3.0))
+                        let g = yn * yk / (y1 * nk) - one;
+                        let dg = if g < F::zero() { one + g } else { one };
+                        let gu = series(g);
+                        let gl = gu - g.powi(4) / (float::<F, _>(4.0) * dg);
+                        let xm = m + half;
+                        let xn = n1_f - m + half;
+                        let xk = k_f - m + half;
+                        let nm = n2_f - k_f + xm;
+                        let ub = xm * series(r)
+                            + xn * series(s)
+                            + xk * series(t)
+                            + nm * series(e)
                             + y * gu
                             - m * gl
-                            + 0.0034;
+                            + float(0.0034);
                         let av = v.ln();
                         if av > ub {
                             continue;
                         }
-                        let dr = if r < 0.0 {
-                            xm * r.powi(4) / (1.0 + r)
+                        let dr = if r < F::zero() {
+                            xm * r.powi(4) / (one + r)
                         } else {
                             xm * r.powi(4)
                         };
-                        let ds = if s < 0.0 {
-                            xn * s.powi(4) / (1.0 + s)
+                        let ds = if s < F::zero() {
+                            xn * s.powi(4) / (one + s)
                         } else {
                             xn * s.powi(4)
                         };
-                        let dt = if t < 0.0 {
-                            xk * t.powi(4) / (1.0 + t)
+                        let dt = if t < F::zero() {
+                            xk * t.powi(4) / (one + t)
                         } else {
                             xk * t.powi(4)
                         };
-                        let de = if e < 0.0 {
-                            nm * e.powi(4) / (1.0 + e)
+                        let de = if e < F::zero() {
+                            nm * e.powi(4) / (one + e)
                         } else {
                             nm * e.powi(4)
                         };
 
-                        if av < ub - 0.25 * (dr + ds + dt + de) + (y + m) * (gl - gu) - 0.0078 {
-                            break y as i64;
+                        if av
+                            < ub - float::<F, _>(0.25) * (dr + ds + dt + de) + (y + m) * (gl - gu)
+                                - float(0.0078)
+                        {
+                            break y.to_i64().unwrap();
                         }
 
                         // Step 4.3: Final Acceptance/Rejection Test
                         let av_critical = a
                             - ln_of_factorial(y)
-                            - ln_of_factorial(n1 as f64 - y)
-                            - ln_of_factorial(k as f64 - y)
-                            - ln_of_factorial((n2 - k) as f64 + y);
+                            - ln_of_factorial(n1_f - y)
+                            - ln_of_factorial(k_f - y)
+                            - ln_of_factorial(float::<F, _>(n2 - k) + y);
                         if v.ln() <= av_critical {
-                            break y as i64;
+                            break y.to_i64().unwrap();
                         }
                     }
                 }
@@ -459,8 +538,13 @@ mod test {
         assert!(Hypergeometric::new(100, 10, 5).is_ok());
     }
 
-    fn test_hypergeometric_mean_and_variance<R: Rng>(n: u64, k: u64, s: u64, rng: &mut R) {
-        let distr = Hypergeometric::new(n, k, s).unwrap();
+    fn test_hypergeometric_mean_and_variance<F, R>(n: u64, k: u64, s: u64, rng: &mut R)
+    where
+        F: Float + SampleUniform,
+        StandardUniform: Distribution<F>,
+        R: Rng,
+    {
+        let distr = Hypergeometric::<F>::new_with_float(n, k, s).unwrap();
 
         let expected_mean = s as f64 * k as f64 / n as f64;
         let expected_variance = {
@@ -487,15 +571,42 @@ mod test {
         let mut rng = crate::test::rng(737);
 
         // exercise algorithm HIN:
-        test_hypergeometric_mean_and_variance(500, 400, 30, &mut rng);
-        test_hypergeometric_mean_and_variance(250, 200, 230, &mut rng);
-        test_hypergeometric_mean_and_variance(100, 20, 6, &mut rng);
-        test_hypergeometric_mean_and_variance(50, 10, 47, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(500, 400, 30, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(250, 200, 230, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(100, 20, 6, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(50, 10, 47, &mut rng);
+
+        // exercise algorithm H2PE
+        test_hypergeometric_mean_and_variance::<f64, _>(5000, 2500, 500, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(10100, 10000, 1000, &mut rng);
+        test_hypergeometric_mean_and_variance::<f64, _>(100100, 100, 10000, &mut rng);
+    }
+
+    #[test]
+    fn test_hypergeometric_f32() {
+        let mut rng = crate::test::rng(738);
+
+        // exercise algorithm HIN:
+        test_hypergeometric_mean_and_variance::<f32, _>(100, 20, 6, &mut rng);
+        test_hypergeometric_mean_and_variance::<f32, _>(50, 10, 47, &mut rng);
 
         // exercise algorithm H2PE
-        test_hypergeometric_mean_and_variance(5000, 2500, 500, &mut rng);
-        test_hypergeometric_mean_and_variance(10100, 10000, 1000, &mut rng);
-        test_hypergeometric_mean_and_variance(100100, 100, 10000, &mut rng);
+        test_hypergeometric_mean_and_variance::<f32, _>(5000, 2500, 500, &mut rng);
+        test_hypergeometric_mean_and_variance::<f32, _>(10100, 10000, 1000, &mut rng);
+
+        // the initial probability of algorithm HIN overflows f32 sooner
+        assert_eq!(
+            Hypergeometric::<f32>::new_with_float(500, 400, 30),
+            Err(Error::PopulationTooLarge)
+        );
+        assert!(Hypergeometric::new(500, 400, 30).is_ok());
+
+        // the constants of algorithm H2PE are not finite
+        assert_eq!(
+            Hypergeometric::<f32>::new_with_float(1 << 26, 1 << 25, 1 << 24),
+            Err(Error::PopulationTooLarge)
+        );
+        assert!(Hypergeometric::new(1 << 26, 1 << 25, 1 << 24).is_ok());
     }
 
     #[test]
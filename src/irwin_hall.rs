@@ -0,0 +1,420 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Irwin–Hall and Bates distributions.
+
+use crate::{Distribution, StandardNormal, StandardUniform};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// Largest `n` for which samples are summed exactly and the distribution
+/// function is evaluated by the exact alternating sum.
+///
+/// The alternating sum loses precision to cancellation, but is still
+/// accurate to about `1e-10` at this limit, while the error of the
+/// Edgeworth expansion used beyond it is below `1e-7` and decreasing in `n`.
+const EXACT_MAX_N: u64 = 40;
+
+/// The [Irwin–Hall distribution](https://en.wikipedia.org/wiki/Irwin%E2%80%93Hall_distribution) `IrwinHall(n)`.
+///
+/// This is the distribution of the sum of `n` independent random variables
+/// uniformly distributed on `[0, 1)`. It is supported on `[0, n]`, has mean
+/// `n / 2` and variance `n / 12`, and approaches a normal distribution as `n`
+/// grows. See [`Bates`] for the distribution of the mean.
+///
+/// # Density function
+///
+/// `f(x) = 1 / (n - 1)! * Σ_{k=0}^{⌊x⌋} (-1)^k * C(n, k) * (x - k)^(n - 1)`
+/// for `0 <= x <= n`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{IrwinHall, Distribution};
+///
+/// let irwin_hall = IrwinHall::<f64>::new(12).unwrap();
+/// let v = irwin_hall.sample(&mut rand::rng());
+/// println!("{} is from an Irwin-Hall(12) distribution", v);
+/// assert!((irwin_hall.cdf(6.0) - 0.5).abs() < 1e-12);
+/// ```
+///
+/// # Notes
+///
+/// For `n <= 40`, samples are the exact sum of `n` uniform samples. For
+/// larger `n`, a [`StandardNormal`] sample is transformed with a
+/// second-order Cornish–Fisher expansion correcting for the (negative)
+/// excess kurtosis; the resulting distribution function differs from the
+/// exact one by less than `1e-7`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrwinHall<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    n: u64,
+    _marker: core::marker::PhantomData<F>,
+}
+
+/// Error type returned from [`IrwinHall::new`] and [`Bates::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `n = 0`.
+    NTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NTooSmall => "n is zero in Irwin-Hall or Bates distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> IrwinHall<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `IrwinHall` distribution of the sum of `n > 0`
+    /// uniform random variables.
+    pub fn new(n: u64) -> Result<IrwinHall<F>, Error> {
+        if n == 0 {
+            return Err(Error::NTooSmall);
+        }
+        Ok(IrwinHall {
+            n,
+            _marker: core::marker::PhantomData,
+        })
+    }
+
+    /// Returns the number of summed uniform random variables.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Evaluate the cumulative distribution function `P(X <= x)`.
+    ///
+    /// The computation is carried out in `f64`. For `n <= 40` the exact
+    /// alternating sum is used, and otherwise an Edgeworth expansion
+    /// accurate to about `1e-7`.
+    pub fn cdf(&self, x: F) -> F {
+        F::from(cdf(self.n, x.to_f64().unwrap())).unwrap()
+    }
+}
+
+impl<F> Distribution<F> for IrwinHall<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        if self.n <= EXACT_MAX_N {
+            let mut sum = F::zero();
+            for _ in 0..self.n {
+                let u: F = rng.sample(StandardUniform);
+                sum = sum + u;
+            }
+            return sum;
+        }
+
+        // Cornish–Fisher expansion for a symmetric distribution with
+        // standardised fourth and sixth cumulants g2 = -6/(5n), g4 = 48/(7n²)
+        let n = F::from(self.n).unwrap();
+        let g2 = F::from(-1.2).unwrap() / n;
+        let g4 = F::from(48.0 / 7.0).unwrap() / (n * n);
+        let z: F = rng.sample(StandardNormal);
+        let z2 = z * z;
+        let he3 = z * (z2 - F::from(3.0).unwrap());
+        let he5 = z * ((z2 - F::from(10.0).unwrap()) * z2 + F::from(15.0).unwrap());
+        let h22 = z
+            * ((F::from(3.0).unwrap() * z2 - F::from(24.0).unwrap()) * z2 + F::from(29.0).unwrap());
+        let w = z + g2 / F::from(24.0).unwrap() * he3 + g4 / F::from(720.0).unwrap() * he5
+            - g2 * g2 / F::from(384.0).unwrap() * h22;
+
+        let half = F::from(0.5).unwrap();
+        let x = n * half + (n / F::from(12.0).unwrap()).sqrt() * w;
+        x.max(F::zero()).min(n)
+    }
+}
+
+/// The [Bates distribution](https://en.wikipedia.org/wiki/Bates_distribution) `Bates(n)`.
+///
+/// This is the distribution of the mean of `n` independent random variables
+/// uniformly distributed on `[0, 1)`, i.e. an [`IrwinHall`] distribution
+/// scaled by `1 / n`. It is supported on `[0, 1]`, has mean `1 / 2` and
+/// variance `1 / (12 n)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Bates, Distribution};
+///
+/// let bates = Bates::<f64>::new(3).unwrap();
+/// let v = bates.sample(&mut rand::rng());
+/// println!("{} is from a Bates(3) distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Sampling and evaluation of the distribution function are delegated to
+/// [`IrwinHall`]; see there for details.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bates<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    irwin_hall: IrwinHall<F>,
+}
+
+impl<F> Bates<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `Bates` distribution of the mean of `n > 0` uniform
+    /// random variables.
+    pub fn new(n: u64) -> Result<Bates<F>, Error> {
+        Ok(Bates {
+            irwin_hall: IrwinHall::new(n)?,
+        })
+    }
+
+    /// Returns the number of averaged uniform random variables.
+    pub fn n(&self) -> u64 {
+        self.irwin_hall.n
+    }
+
+    /// Evaluate the cumulative distribution function `P(X <= x)`.
+    ///
+    /// See [`IrwinHall::cdf`] for details on the computation.
+    pub fn cdf(&self, x: F) -> F {
+        self.irwin_hall.cdf(x * F::from(self.irwin_hall.n).unwrap())
+    }
+}
+
+impl<F> Distribution<F> for Bates<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.irwin_hall.sample(rng) / F::from(self.irwin_hall.n).unwrap()
+    }
+}
+
+/// Cumulative distribution function of the sum of `n` uniform variables.
+fn cdf(n: u64, x: f64) -> f64 {
+    let nf = n as f64;
+    if x.is_nan() {
+        return x;
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= nf {
+        return 1.0;
+    }
+    // Evaluate the lower tail to reduce cancellation
+    if x > 0.5 * nf {
+        return 1.0 - cdf(n, nf - x);
+    }
+
+    if n <= EXACT_MAX_N {
+        exact_cdf(n, x)
+    } else {
+        edgeworth_cdf(n, x)
+    }
+}
+
+/// `Σ_{k=0}^{⌊x⌋} (-1)^k (x - k)^n / (k! (n - k)!)`
+fn exact_cdf(n: u64, x: f64) -> f64 {
+    let mut sum = 0.0;
+    for k in 0..=(x.floor() as u64).min(n) {
+        let y = x - k as f64;
+        // Interleave the factors to avoid overflow for large `n`
+        let mut term = 1.0;
+        for j in 1..=k {
+            term *= y / j as f64;
+        }
+        for j in 1..=(n - k) {
+            term *= y / j as f64;
+        }
+        if k % 2 == 0 {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+    }
+    sum.clamp(0.0, 1.0)
+}
+
+/// Edgeworth expansion to order `1/n²`, using that the distribution is
+/// symmetric.
+fn edgeworth_cdf(n: u64, x: f64) -> f64 {
+    let nf = n as f64;
+    let g2 = -1.2 / nf;
+    let g4 = 48.0 / 7.0 / (nf * nf);
+    let z = (x - 0.5 * nf) / (nf / 12.0).sqrt();
+    let z2 = z * z;
+    let he3 = z * (z2 - 3.0);
+    let he5 = z * ((z2 - 10.0) * z2 + 15.0);
+    let he7 = z * (((z2 - 21.0) * z2 + 105.0) * z2 - 105.0);
+    let pdf = (-0.5 * z2).exp() / (2.0 * core::f64::consts::PI).sqrt();
+    let correction = g2 / 24.0 * he3 + g4 / 720.0 * he5 + g2 * g2 / 1152.0 * he7;
+    (standard_normal_cdf(z) - pdf * correction).clamp(0.0, 1.0)
+}
+
+/// Standard normal distribution function, using Hart's double precision
+/// algorithm as given by West (2005), *Better approximations to cumulative
+/// normal functions*.
+fn standard_normal_cdf(z: f64) -> f64 {
+    let x = z.abs();
+    let tail = if x > 37.0 {
+        0.0
+    } else {
+        let e = (-0.5 * x * x).exp();
+        if x < 7.07106781186547 {
+            let mut num = 3.52624965998911e-02 * x + 0.700383064443688;
+            num = num * x + 6.37396220353165;
+            num = num * x + 33.912866078383;
+            num = num * x + 112.079291497871;
+            num = num * x + 221.213596169931;
+            num = num * x + 220.206867912376;
+            let mut den = 8.83883476483184e-02 * x + 1.75566716318264;
+            den = den * x + 16.064177579207;
+            den = den * x + 86.7807322029461;
+            den = den * x + 296.564248779674;
+            den = den * x + 637.333633378831;
+            den = den * x + 793.826512519948;
+            den = den * x + 440.413735824752;
+            e * num / den
+        } else {
+            let mut b = x + 0.65;
+            b = x + 4.0 / b;
+            b = x + 3.0 / b;
+            b = x + 2.0 / b;
+            b = x + 1.0 / b;
+            e / b / 2.506628274631
+        }
+    };
+    if z > 0.0 { 1.0 - tail } else { tail }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(IrwinHall::<f64>::new(0), Err(Error::NTooSmall));
+        assert_eq!(Bates::<f32>::new(0), Err(Error::NTooSmall));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let d = IrwinHall::<f64>::new(1).unwrap();
+        for &x in &[0.0, 0.1, 0.5, 0.99] {
+            assert_almost_eq!(d.cdf(x), x, 1e-15);
+        }
+        let d = IrwinHall::<f64>::new(2).unwrap();
+        assert_almost_eq!(d.cdf(0.5), 0.125, 1e-15);
+        assert_almost_eq!(d.cdf(1.5), 0.875, 1e-15);
+        assert_eq!(d.cdf(-1.0), 0.0);
+        assert_eq!(d.cdf(3.0), 1.0);
+        let d = IrwinHall::<f64>::new(3).unwrap();
+        assert_almost_eq!(d.cdf(1.0), 1.0 / 6.0, 1e-15);
+        assert_almost_eq!(d.cdf(2.0), 5.0 / 6.0, 1e-15);
+        for n in [5, 40, 41, 1000] {
+            let d = IrwinHall::<f64>::new(n).unwrap();
+            assert_almost_eq!(d.cdf(n as f64 / 2.0), 0.5, 1e-10);
+        }
+
+        let d = Bates::<f32>::new(2).unwrap();
+        assert_almost_eq!(d.cdf(0.25), 0.125, 1e-7);
+    }
+
+    #[test]
+    fn cdf_known_values() {
+        // Exact values, computed with rational arithmetic
+        let exact = [
+            (4, 1.0, 1.0 / 24.0),
+            (4, 2.5, 0.7994791666666666),
+            (5, 2.0, 0.225),
+            (12, 4.0, 0.022275583213083212),
+        ];
+        for (n, x, p) in exact {
+            let d = IrwinHall::<f64>::new(n).unwrap();
+            assert_almost_eq!(d.cdf(x), p, 1e-14);
+            let b = Bates::<f64>::new(n).unwrap();
+            assert_almost_eq!(b.cdf(x / n as f64), p, 1e-14);
+        }
+
+        // The Edgeworth expansion is used for these
+        let approximate = [
+            (100, 45.0, 0.04163230481080177),
+            (100, 40.0, 0.00025065623009830237),
+            (1000, 480.0, 0.014222727295134416),
+        ];
+        for (n, x, p) in approximate {
+            let d = IrwinHall::<f64>::new(n).unwrap();
+            assert_almost_eq!(d.cdf(x), p, 1e-7);
+            assert_almost_eq!(d.cdf(n as f64 - x), 1.0 - p, 1e-7);
+        }
+    }
+
+    #[test]
+    fn edgeworth_matches_exact() {
+        // The exact sum is still accurate to about 1e-10 for these n
+        for n in [41, 45] {
+            for i in 1..=30 {
+                let x = n as f64 * i as f64 / 60.0;
+                assert_almost_eq!(edgeworth_cdf(n, x), exact_cdf(n, x), 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_standard_normal_cdf() {
+        assert_eq!(standard_normal_cdf(0.0), 0.5);
+        assert_almost_eq!(standard_normal_cdf(1.0), 0.8413447460685429, 1e-14);
+        assert_almost_eq!(standard_normal_cdf(-3.0), 0.0013498980316301, 1e-15);
+        assert_almost_eq!(standard_normal_cdf(-10.0), 7.61985302416047e-24, 1e-31);
+    }
+
+    #[test]
+    fn sample() {
+        let mut rng = crate::test::rng(321);
+        for n in [1, 7, 50, 51, 400] {
+            let d = IrwinHall::<f64>::new(n).unwrap();
+            let b = Bates::<f64>::new(n).unwrap();
+            for _ in 0..100 {
+                assert!((0.0..=n as f64).contains(&d.sample(&mut rng)));
+                assert!((0.0..=1.0).contains(&b.sample(&mut rng)));
+            }
+        }
+    }
+
+    #[test]
+    fn irwin_hall_distributions_can_be_compared() {
+        assert_eq!(IrwinHall::<f64>::new(3), IrwinHall::<f64>::new(3));
+        assert_eq!(Bates::<f64>::new(3), Bates::<f64>::new(3));
+    }
+}
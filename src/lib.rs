@@ -45,39 +45,72 @@
 //!   - [`Normal`] distribution, and [`StandardNormal`] as a primitive
 //!   - [`SkewNormal`] distribution
 //!   - [`Cauchy`] distribution
+//! - Related to non-negative scale parameters (e.g. weakly informative priors):
+//!   - [`HalfNormal`] and [`FoldedNormal`] distributions
+//!   - [`HalfCauchy`] distribution
+//!   - [`HalfStudentT`] distribution
 //! - Related to Bernoulli trials (yes/no events, with a given probability):
 //!   - [`Binomial`] distribution
 //!   - [`Geometric`] distribution
+//!   - [`DiscreteLaplace`] (two-sided geometric) distribution
+//!   - [`NegativeBinomial`] distribution
+//!   - [`BetaBinomial`] and [`BetaNegativeBinomial`] distributions
+//!   - [`PoissonBinomial`] distribution
 //!   - [`Hypergeometric`] distribution
+//!   - [`ZeroInflated`] and [`Hurdle`] count distributions
 //! - Related to positive real-valued quantities that grow exponentially
 //!   (e.g. prices, incomes, populations):
 //!   - [`LogNormal`] distribution
+//!   - [`LogUniform`] distribution
+//!   - [`LogTransformed`] and [`ExpTransformed`] wrappers of other distributions
 //! - Related to the occurrence of independent events at a given rate:
 //!   - [`Pareto`] distribution
 //!   - [`Poisson`] distribution
+//!   - [`ConwayMaxwellPoisson`] and [`GeneralizedPoisson`] distributions
+//!   - [`Skellam`] distribution
+//!   - [`CompoundPoisson`] and [`Tweedie`] distributions
 //!   - [`Exp`]onential distribution, and [`Exp1`] as a primitive
 //!   - [`Weibull`] distribution
 //!   - [`Gumbel`] distribution
 //!   - [`Frechet`] distribution
+//!   - [`Gompertz`] and [`GompertzMakeham`] distributions
+//!   - [`Lomax`] distribution
+//!   - [`BurrXII`] distribution
 //!   - [`Zeta`] distribution
 //!   - [`Zipf`] distribution
+//!   - [`ZipfMandelbrot`] distribution
+//!   - [`LogarithmicSeries`] distribution
+//!   - [`YuleSimon`] distribution
 //! - Gamma and derived distributions:
 //!   - [`Gamma`] distribution
 //!   - [`ChiSquared`] distribution
+//!   - [`Chi`] distribution
+//!   - [`MaxwellBoltzmann`] distribution
+//!   - [`Nakagami`] distribution
 //!   - [`StudentT`] distribution
 //!   - [`FisherF`] distribution
 //! - Triangular distribution:
 //!   - [`Beta`] distribution
 //!   - [`Triangular`] distribution
+//!   - [`Trapezoidal`] distribution
+//!   - [`PiecewiseConstant`] and [`PiecewiseLinear`] distributions
 //! - Multivariate probability distributions
 //!   - [`multi::Dirichlet`] distribution
+//!   - [`multi::Multinomial`] distribution
+//!   - [`multi::MultivariateHypergeometric`] distribution
+//!   - [`multi::DirichletMultinomial`] distribution
 //!   - [`UnitSphere`] distribution
 //!   - [`UnitBall`] distribution
 //!   - [`UnitCircle`] distribution
 //!   - [`UnitDisc`] distribution
 //! - Misc. distributions
+//!   - [`Empirical`] distribution of observations, optionally smoothed
+//!   - [`Mixture`] and [`DynMixture`] of other distributions
 //!   - [`InverseGaussian`] distribution
 //!   - [`NormalInverseGaussian`] distribution
+//!   - [`ExGaussian`] distribution
+//!   - [`VarianceGamma`] distribution
+//!   - [`IrwinHall`] and [`Bates`] distributions
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -95,17 +128,35 @@ pub use rand::distr::{
 };
 
 pub use self::beta::{Beta, Error as BetaError};
+pub use self::beta_binomial::{BetaBinomial, BetaNegativeBinomial, Error as BetaBinomialError};
 pub use self::binomial::{Binomial, Error as BinomialError};
-pub use self::cauchy::{Cauchy, Error as CauchyError};
+pub use self::burr::{BurrXII, Error as BurrXIIError};
+pub use self::cauchy::{Cauchy, Error as CauchyError, HalfCauchy};
+pub use self::chi::{Chi, Error as ChiError};
 pub use self::chi_squared::{ChiSquared, Error as ChiSquaredError};
+pub use self::compound_poisson::{CompoundPoisson, Error as TweedieError, Tweedie};
+pub use self::conway_maxwell_poisson::{ConwayMaxwellPoisson, Error as ConwayMaxwellPoissonError};
+pub use self::discrete_laplace::{DiscreteLaplace, Error as DiscreteLaplaceError};
+pub use self::ex_gaussian::{Error as ExGaussianError, ExGaussian};
 pub use self::exponential::{Error as ExpError, Exp, Exp1};
 pub use self::fisher_f::{Error as FisherFError, FisherF};
+pub use self::folded_normal::{Error as FoldedNormalError, FoldedNormal, HalfNormal};
 pub use self::frechet::{Error as FrechetError, Frechet};
 pub use self::gamma::{Error as GammaError, Gamma};
+pub use self::generalized_poisson::{Error as GeneralizedPoissonError, GeneralizedPoisson};
 pub use self::geometric::{Error as GeoError, Geometric, StandardGeometric};
+pub use self::gompertz::{Error as GompertzError, Gompertz, GompertzMakeham};
 pub use self::gumbel::{Error as GumbelError, Gumbel};
 pub use self::hypergeometric::{Error as HyperGeoError, Hypergeometric};
 pub use self::inverse_gaussian::{Error as InverseGaussianError, InverseGaussian};
+pub use self::irwin_hall::{Bates, Error as IrwinHallError, IrwinHall};
+pub use self::log_transformed::{ExpTransformed, LogTransformed};
+pub use self::log_uniform::{Error as LogUniformError, LogUniform};
+pub use self::logarithmic_series::{Error as LogarithmicSeriesError, LogarithmicSeries};
+pub use self::lomax::{Error as LomaxError, Lomax};
+pub use self::maxwell_boltzmann::{Error as MaxwellBoltzmannError, MaxwellBoltzmann};
+pub use self::nakagami::{Error as NakagamiError, Nakagami};
+pub use self::negative_binomial::{Error as NegativeBinomialError, NegativeBinomial};
 pub use self::normal::{Error as NormalError, LogNormal, Normal, StandardNormal};
 pub use self::normal_inverse_gaussian::{
     Error as NormalInverseGaussianError, NormalInverseGaussian,
@@ -113,16 +164,33 @@ pub use self::normal_inverse_gaussian::{
 pub use self::pareto::{Error as ParetoError, Pareto};
 pub use self::pert::{Pert, PertBuilder, PertError};
 pub use self::poisson::{Error as PoissonError, Poisson};
+pub use self::skellam::{Error as SkellamError, Skellam};
 pub use self::skew_normal::{Error as SkewNormalError, SkewNormal};
+pub use self::student_t::{Error as StudentTError, HalfStudentT, StudentT};
+pub use self::trapezoidal::{Trapezoidal, TrapezoidalError};
 pub use self::triangular::{Triangular, TriangularError};
 pub use self::unit_ball::UnitBall;
 pub use self::unit_circle::UnitCircle;
 pub use self::unit_disc::UnitDisc;
 pub use self::unit_sphere::UnitSphere;
+pub use self::variance_gamma::{Error as VarianceGammaError, VarianceGamma};
 pub use self::weibull::{Error as WeibullError, Weibull};
+pub use self::yule_simon::{Error as YuleSimonError, YuleSimon};
+pub use self::zero_inflated::{
+    Error as ZeroInflatedError, Hurdle, SampleNonZero, ZeroInflated, ZeroProbability,
+};
 pub use self::zeta::{Error as ZetaError, Zeta};
 pub use self::zipf::{Error as ZipfError, Zipf};
-pub use student_t::StudentT;
+pub use self::zipf_mandelbrot::{Error as ZipfMandelbrotError, ZipfMandelbrot};
+
+#[cfg(feature = "alloc")]
+pub use self::empirical::{Bandwidth, Empirical, Error as EmpiricalError, Kernel};
+#[cfg(feature = "alloc")]
+pub use self::mixture::{DynDistribution, DynMixture, Mixture};
+#[cfg(feature = "alloc")]
+pub use self::piecewise::{Error as PiecewiseError, PiecewiseConstant, PiecewiseLinear};
+#[cfg(feature = "alloc")]
+pub use self::poisson_binomial::{Error as PoissonBinomialError, PoissonBinomial};
 
 pub use num_traits;
 
@@ -198,31 +266,63 @@ mod test {
 }
 
 mod beta;
+mod beta_binomial;
 mod binomial;
+mod burr;
 mod cauchy;
+mod chi;
 mod chi_squared;
+mod compound_poisson;
+mod conway_maxwell_poisson;
+mod discrete_laplace;
+#[cfg(feature = "alloc")]
+mod empirical;
+mod ex_gaussian;
 mod exponential;
 mod fisher_f;
+mod folded_normal;
 mod frechet;
 mod gamma;
+mod generalized_poisson;
 mod geometric;
+mod gompertz;
 mod gumbel;
 mod hypergeometric;
 mod inverse_gaussian;
+mod irwin_hall;
+mod log_transformed;
+mod log_uniform;
+mod logarithmic_series;
+mod lomax;
+mod maxwell_boltzmann;
+#[cfg(feature = "alloc")]
+mod mixture;
+mod nakagami;
+mod negative_binomial;
 mod normal;
 mod normal_inverse_gaussian;
 mod pareto;
 mod pert;
+#[cfg(feature = "alloc")]
+mod piecewise;
 pub(crate) mod poisson;
+#[cfg(feature = "alloc")]
+mod poisson_binomial;
+mod skellam;
 mod skew_normal;
 mod student_t;
+mod trapezoidal;
 mod triangular;
 mod unit_ball;
 mod unit_circle;
 mod unit_disc;
 mod unit_sphere;
 mod utils;
+mod variance_gamma;
 mod weibull;
+mod yule_simon;
+mod zero_inflated;
 mod zeta;
 mod ziggurat_tables;
 mod zipf;
+mod zipf_mandelbrot;
@@ -0,0 +1,150 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Log- and exp-transformed distributions.
+
+use crate::Distribution;
+use num_traits::Float;
+use rand::Rng;
+
+/// The [log-transformed](https://en.wikipedia.org/wiki/Log-distributions)
+/// version of a real-valued distribution `D`.
+///
+/// A random variable `X` follows the log-`D` distribution if `ln(X)` follows
+/// `D`; samples are thus `exp(Y)` where `Y ~ D`. For example,
+/// `LogTransformed<Cauchy<F>>` is the log-Cauchy distribution,
+/// `LogTransformed<StudentT<F>>` the log-t distribution, and
+/// `LogTransformed<Normal<F>>` is equivalent to [`LogNormal`](crate::LogNormal).
+///
+/// See [`ExpTransformed`] for the inverse transformation.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Cauchy, Distribution, LogTransformed};
+///
+/// let log_cauchy = LogTransformed::new(Cauchy::new(0.0, 1.0).unwrap());
+/// let v: f64 = log_cauchy.sample(&mut rand::rng());
+/// println!("{} is from a log-Cauchy(0, 1) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogTransformed<D> {
+    distr: D,
+}
+
+impl<D> LogTransformed<D> {
+    /// Construct the log-transformed version of `distr`.
+    pub fn new(distr: D) -> LogTransformed<D> {
+        LogTransformed { distr }
+    }
+
+    /// Returns a reference to the distribution of `ln(X)`.
+    pub fn inner(&self) -> &D {
+        &self.distr
+    }
+}
+
+impl<F, D> Distribution<F> for LogTransformed<D>
+where
+    F: Float,
+    D: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.distr.sample(rng).exp()
+    }
+}
+
+/// The exp-transformed version of a positive-valued distribution `D`.
+///
+/// A random variable `X` follows the exp-`D` distribution if `exp(X)`
+/// follows `D`; samples are thus `ln(Y)` where `Y ~ D`. For example,
+/// `ExpTransformed<Gamma<F>>` is the log-gamma distribution (of the
+/// logarithm of a gamma random variable) and
+/// `ExpTransformed<LogNormal<F>>` is equivalent to
+/// [`Normal`](crate::Normal).
+///
+/// See [`LogTransformed`] for the inverse transformation.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, ExpTransformed, Gamma};
+///
+/// let log_gamma = ExpTransformed::new(Gamma::new(2.0, 1.0).unwrap());
+/// let v: f64 = log_gamma.sample(&mut rand::rng());
+/// println!("{} is the logarithm of a Gamma(2, 1) sample", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples of `D` which are zero are mapped to `-inf`, and negative samples
+/// to `nan`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExpTransformed<D> {
+    distr: D,
+}
+
+impl<D> ExpTransformed<D> {
+    /// Construct the exp-transformed version of `distr`.
+    pub fn new(distr: D) -> ExpTransformed<D> {
+        ExpTransformed { distr }
+    }
+
+    /// Returns a reference to the distribution of `exp(X)`.
+    pub fn inner(&self) -> &D {
+        &self.distr
+    }
+}
+
+impl<F, D> Distribution<F> for ExpTransformed<D>
+where
+    F: Float,
+    D: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.distr.sample(rng).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LogNormal, Normal};
+
+    #[test]
+    fn log_normal_equivalence() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        let log_normal = LogNormal::new(1.0, 2.0).unwrap();
+        let log_transformed = LogTransformed::new(normal);
+        let exp_transformed = ExpTransformed::new(log_normal);
+        assert_eq!(log_transformed.inner(), &normal);
+        assert_eq!(exp_transformed.inner(), &log_normal);
+
+        let mut rng1 = crate::test::rng(331);
+        let mut rng2 = crate::test::rng(331);
+        for _ in 0..100 {
+            assert_eq!(
+                log_transformed.sample(&mut rng1),
+                log_normal.sample(&mut rng2)
+            );
+        }
+        for _ in 0..100 {
+            let x = exp_transformed.sample(&mut rng1);
+            assert_almost_eq!(x, normal.sample(&mut rng2), 1e-12);
+        }
+    }
+
+    #[test]
+    fn log_transformed_distributions_can_be_compared() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(LogTransformed::new(normal), LogTransformed::new(normal));
+        assert_eq!(ExpTransformed::new(normal), ExpTransformed::new(normal));
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The log-uniform distribution `LogUniform(a, b)`.
+
+use crate::{Distribution, StandardUniform};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [log-uniform distribution](https://en.wikipedia.org/wiki/Reciprocal_distribution) `LogUniform(a, b)`.
+///
+/// Also known as the reciprocal distribution, this is the distribution of
+/// `X` on `[a, b]` such that `ln(X)` is uniformly distributed on
+/// `[ln(a), ln(b)]`. Every order of magnitude within the range is thus
+/// equally likely, which makes it a common choice for sampling scale
+/// hyperparameters such as learning rates.
+///
+/// # Density function
+///
+/// `f(x) = 1 / (x * ln(b / a))` for `a <= x <= b`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{LogUniform, Distribution};
+///
+/// let learning_rate = LogUniform::new(1e-5, 1e-1).unwrap();
+/// let v = learning_rate.sample(&mut rand::rng());
+/// println!("{} is from a LogUniform(1e-5, 0.1) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogUniform<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    low: F,
+    high: F,
+    ln_low: F,
+    ln_ratio: F,
+}
+
+/// Error type returned from [`LogUniform::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `low <= 0` or `nan`.
+    LowNotPositive,
+    /// `high < low`, or `high` is infinite or `nan`.
+    RangeTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LowNotPositive => "low is not positive in log-uniform distribution",
+            Error::RangeTooSmall => {
+                "requirement low <= high < inf is not met in log-uniform distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> LogUniform<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    /// Construct a new `LogUniform` distribution on the range `[low, high]`,
+    /// where `0 < low <= high < inf`.
+    pub fn new(low: F, high: F) -> Result<LogUniform<F>, Error> {
+        if !(low > F::zero()) {
+            return Err(Error::LowNotPositive);
+        }
+        if !(high >= low) || high.is_infinite() {
+            return Err(Error::RangeTooSmall);
+        }
+        // `high / low` may overflow for wide ranges, so work in log space
+        let ln_low = low.ln();
+        Ok(LogUniform {
+            low,
+            high,
+            ln_low,
+            ln_ratio: high.ln() - ln_low,
+        })
+    }
+
+    /// Returns the lower bound of the range.
+    pub fn low(&self) -> F {
+        self.low
+    }
+
+    /// Returns the upper bound of the range.
+    pub fn high(&self) -> F {
+        self.high
+    }
+}
+
+impl<F> Distribution<F> for LogUniform<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let u: F = rng.sample(StandardUniform);
+        // Rounding may slightly overshoot the bounds
+        (self.ln_low + u * self.ln_ratio)
+            .exp()
+            .max(self.low)
+            .min(self.high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ConstRng;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(LogUniform::new(0.0, 1.0), Err(Error::LowNotPositive));
+        assert_eq!(LogUniform::new(f64::NAN, 1.0), Err(Error::LowNotPositive));
+        assert_eq!(LogUniform::new(2.0, 1.0), Err(Error::RangeTooSmall));
+        assert_eq!(LogUniform::new(1.0, f64::NAN), Err(Error::RangeTooSmall));
+        assert_eq!(
+            LogUniform::new(1.0, f64::INFINITY),
+            Err(Error::RangeTooSmall)
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let mut half_rng = ConstRng(0x8000_0000_0000_0000);
+        let d = LogUniform::new(1e-4, 1.0).unwrap();
+        assert_almost_eq!(d.sample(&mut half_rng), 1e-2, 1e-15);
+
+        let d = LogUniform::new(3.0, 3.0).unwrap();
+        assert_eq!(d.sample(&mut half_rng), 3.0);
+
+        let d = LogUniform::new(1e-10f32, 1e10).unwrap();
+        assert_eq!((d.low(), d.high()), (1e-10, 1e10));
+        let mut rng = crate::test::rng(341);
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng);
+            assert!((1e-10..=1e10).contains(&x));
+        }
+    }
+
+    #[test]
+    fn wide_range() {
+        // high / low overflows f32
+        let d = LogUniform::new(1e-30f32, 1e30).unwrap();
+        let mut half_rng = ConstRng(0x8000_0000_8000_0000);
+        assert_almost_eq!(d.sample(&mut half_rng), 1.0, 1e-5);
+
+        let mut rng = crate::test::rng(342);
+        let mut below_one = 0;
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng);
+            assert!((1e-30..=1e30).contains(&x));
+            below_one += (x < 1.0) as u32;
+        }
+        assert!((400..600).contains(&below_one));
+
+        let d = LogUniform::new(1e-300, 1e100).unwrap();
+        assert_almost_eq!(d.sample(&mut half_rng).log10(), -100.0, 1e-6);
+    }
+
+    #[test]
+    fn log_uniform_distributions_can_be_compared() {
+        assert_eq!(LogUniform::new(1.0, 2.0), LogUniform::new(1.0, 2.0));
+    }
+}
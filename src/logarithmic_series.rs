@@ -0,0 +1,159 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The logarithmic series distribution `LogarithmicSeries(p)`.
+
+use crate::Distribution;
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [logarithmic series distribution](https://en.wikipedia.org/wiki/Logarithmic_distribution)
+/// `LogarithmicSeries(p)`.
+///
+/// This discrete distribution on the positive integers arises as the limit
+/// of the zero-truncated [`NegativeBinomial`](crate::NegativeBinomial)
+/// distribution as `r → 0`. It was introduced by Fisher to model the number
+/// of individuals per species in a sample, and the distribution of the
+/// number of species with a given abundance.
+///
+/// # Density function
+///
+/// `f(k) = -p^k / (k ln(1 - p))` for `k >= 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, LogarithmicSeries};
+///
+/// let log_series = LogarithmicSeries::new(0.9).unwrap();
+/// let v = log_series.sample(&mut rand::rng());
+/// println!("{} is from a logarithmic series distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated with the algorithm LK of Kemp, which requires on
+/// average less than two uniform samples:
+///
+/// A. W. Kemp. 1981. Efficient Generation of Logarithmically Distributed
+/// Pseudo-Random Variables. Journal of the Royal Statistical Society.
+/// Series C (Applied Statistics) 30, 3 (1981), 249–253.
+/// <https://doi.org/10.2307/2346348>
+///
+/// For `p` very close to 1 the samples may exceed `u64::MAX`, in which case
+/// they saturate to `u64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogarithmicSeries {
+    p: f64,
+    // ln(1 - p)
+    r: f64,
+}
+
+/// Error type returned from [`LogarithmicSeries::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `p <= 0`, `p >= 1` or `nan`.
+    InvalidProbability,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidProbability => {
+                "p is NaN or outside the interval (0, 1) in logarithmic series distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl LogarithmicSeries {
+    /// Construct a new `LogarithmicSeries` distribution with shape parameter
+    /// `p`.
+    pub fn new(p: f64) -> Result<LogarithmicSeries, Error> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(Error::InvalidProbability);
+        }
+        Ok(LogarithmicSeries { p, r: (-p).ln_1p() })
+    }
+
+    /// Returns the shape parameter `p`.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Distribution<u64> for LogarithmicSeries {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let v: f64 = rng.random();
+            if v >= self.p {
+                return 1;
+            }
+            let u: f64 = rng.random();
+            let q = -(self.r * u).exp_m1();
+            if v <= q * q {
+                let k = (1.0 + v.ln() / q.ln()).floor();
+                if !(k >= 1.0) {
+                    // v == 0 or u == 0
+                    continue;
+                }
+                return k as u64;
+            }
+            return if v >= q { 1 } else { 2 };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_p() {
+        for p in [0.0, 1.0, -0.5, 1.5, f64::NAN, f64::INFINITY] {
+            assert_eq!(LogarithmicSeries::new(p), Err(Error::InvalidProbability));
+        }
+        assert!(LogarithmicSeries::new(1e-300).is_ok());
+        assert!(LogarithmicSeries::new(1.0 - f64::EPSILON).is_ok());
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(441);
+        for p in [0.2, 0.6, 0.9] {
+            let d = LogarithmicSeries::new(p).unwrap();
+            let r = (-p).ln_1p();
+            let expected_mean = -p / ((1.0 - p) * r);
+            let expected_variance = -p * (p + r) / ((1.0 - p) * r).powi(2);
+
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+            assert!(results.iter().all(|&x| x >= 1.0));
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < expected_mean / 50.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 5.0);
+        }
+    }
+
+    #[test]
+    fn logarithmic_series_distributions_can_be_compared() {
+        assert_eq!(LogarithmicSeries::new(0.5), LogarithmicSeries::new(0.5));
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Lomax distribution `Lomax(λ, α)`.
+
+use crate::{Distribution, Exp1};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Lomax distribution](https://en.wikipedia.org/wiki/Lomax_distribution) `Lomax(λ, α)`.
+///
+/// Also known as the Pareto Type II distribution, this is a heavy-tailed
+/// continuous probability distribution with scale parameter `λ` (`lambda`)
+/// and shape parameter `α` (`alpha`). It is the [`Pareto`](crate::Pareto)
+/// distribution shifted to start at zero: if `X ~ Pareto(λ, α)` then
+/// `X - λ ~ Lomax(λ, α)`. Its hazard rate `α / (x + λ)` decreases with `x`.
+///
+/// # Density function
+///
+/// `f(x; λ, α) = (α / λ) * (1 + x / λ)^-(α + 1)` for `x >= 0`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::Lomax;
+///
+/// let val: f64 = rand::rng().sample(Lomax::new(1., 2.).unwrap());
+/// println!("{}", val);
+/// ```
+///
+/// # Notes
+///
+/// Sampling uses inversion of the distribution function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lomax<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    scale: F,
+    inv_shape: F,
+}
+
+/// Error type returned from [`Lomax::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+    /// `shape <= 0` or `nan`.
+    ShapeTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ScaleTooSmall => "scale is not positive in Lomax distribution",
+            Error::ShapeTooSmall => "shape is not positive in Lomax distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Lomax<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    /// Construct a new `Lomax` distribution with given `scale` and `shape`.
+    ///
+    /// In the literature, `scale` is commonly written as `λ` and `shape` as
+    /// `α`.
+    pub fn new(scale: F, shape: F) -> Result<Lomax<F>, Error> {
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        if !(shape > F::zero()) {
+            return Err(Error::ShapeTooSmall);
+        }
+        Ok(Lomax {
+            scale,
+            inv_shape: F::one() / shape,
+        })
+    }
+}
+
+impl<F> Distribution<F> for Lomax<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // Inverting F(x) = 1 - (1 + x / λ)^-α with E = -ln(1 - U):
+        let e: F = rng.sample(Exp1);
+        self.scale * (e * self.inv_shape).exp_m1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn invalid() {
+        Lomax::new(0., 0.).unwrap();
+    }
+
+    #[test]
+    fn sample() {
+        let d = Lomax::new(1.0, 2.0).unwrap();
+        let mut rng = crate::test::rng(1);
+        for _ in 0..1000 {
+            let r = d.sample(&mut rng);
+            assert!(r >= 0.);
+        }
+    }
+
+    #[test]
+    fn value_stability() {
+        fn test_samples<F: Float + fmt::Debug, D: Distribution<F>>(
+            distr: D,
+            zero: F,
+            expected: &[F],
+        ) {
+            let mut rng = crate::test::rng(213);
+            let mut buf = [zero; 4];
+            for x in &mut buf {
+                *x = rng.sample(&distr);
+            }
+            assert_eq!(buf, expected);
+        }
+
+        test_samples(
+            Lomax::new(1.0, 1.0).unwrap(),
+            0f32,
+            &[2.5838535, 6.705185, 0.36200154, 0.29477707],
+        );
+        test_samples(
+            Lomax::new(2.0, 0.5).unwrap(),
+            0f64,
+            &[
+                23.68800839933317,
+                116.73974434686521,
+                1.7100963749142126,
+                1.3528953923189206,
+            ],
+        );
+    }
+
+    #[test]
+    fn lomax_distributions_can_be_compared() {
+        assert_eq!(Lomax::new(1.0, 2.0), Lomax::new(1.0, 2.0));
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Maxwell–Boltzmann distribution `MB(a)`.
+
+use crate::{Distribution, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Maxwell–Boltzmann distribution](https://en.wikipedia.org/wiki/Maxwell%E2%80%93Boltzmann_distribution) `MB(a)`.
+///
+/// This is the distribution of particle speeds in an ideal gas, with scale
+/// parameter `a = sqrt(kT / m)`. It is the Euclidean norm of a vector of
+/// three independent `N(0, a²)` velocity components, i.e. `a` times a
+/// [`Chi`](crate::Chi) distribution with `k = 3` degrees of freedom.
+///
+/// # Density function
+///
+/// `f(x) = sqrt(2 / π) * x² * exp(-x² / (2a²)) / a³` for `x >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{MaxwellBoltzmann, Distribution};
+///
+/// let mb = MaxwellBoltzmann::new(2.0).unwrap();
+/// let v = mb.sample(&mut rand::rng());
+/// println!("{} is from a Maxwell-Boltzmann(2) distribution", v)
+/// ```
+///
+/// # Notes
+///
+/// Samples are computed directly from three [`StandardNormal`] draws, which
+/// is faster than going through the [`Gamma`](crate::Gamma) distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxwellBoltzmann<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    scale: F,
+}
+
+/// Error type returned from [`MaxwellBoltzmann::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `scale <= 0` or `nan`.
+    ScaleTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ScaleTooSmall => "scale is not positive in Maxwell-Boltzmann distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> MaxwellBoltzmann<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `MaxwellBoltzmann` distribution with the given
+    /// `scale` (`a`).
+    pub fn new(scale: F) -> Result<MaxwellBoltzmann<F>, Error> {
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleTooSmall);
+        }
+        Ok(MaxwellBoltzmann { scale })
+    }
+}
+
+impl<F> Distribution<F> for MaxwellBoltzmann<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let x: F = rng.sample(StandardNormal);
+        let y: F = rng.sample(StandardNormal);
+        let z: F = rng.sample(StandardNormal);
+        self.scale * (x * x + y * y + z * z).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn invalid() {
+        MaxwellBoltzmann::new(0.).unwrap();
+    }
+
+    #[test]
+    fn sample() {
+        let d = MaxwellBoltzmann::new(2.0).unwrap();
+        let mut rng = crate::test::rng(1);
+        for _ in 0..1000 {
+            let r = d.sample(&mut rng);
+            assert!(r >= 0.);
+        }
+    }
+
+    #[test]
+    fn maxwell_boltzmann_distributions_can_be_compared() {
+        assert_eq!(MaxwellBoltzmann::new(1.0), MaxwellBoltzmann::new(1.0));
+    }
+}
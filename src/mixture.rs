@@ -0,0 +1,283 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Finite mixture distributions.
+
+use crate::weighted::{AliasableWeight, Error, WeightedAliasIndex};
+use crate::{Distribution, Uniform};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A finite [mixture distribution](https://en.wikipedia.org/wiki/Mixture_distribution)
+/// of components of the same type.
+///
+/// Sampling first selects a component with probability proportional to its
+/// weight, and then returns a sample of that component. For components of
+/// different types, see [`DynMixture`].
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Mixture, Normal};
+///
+/// // A bimodal Gaussian mixture
+/// let components = vec![Normal::new(-2.0, 0.5).unwrap(), Normal::new(3.0, 1.0).unwrap()];
+/// let mixture = Mixture::new(components, vec![0.3, 0.7]).unwrap();
+/// let v = mixture.sample(&mut rand::rng());
+/// println!("{} is from a Gaussian mixture", v);
+/// ```
+///
+/// # Notes
+///
+/// The component is selected with a [`WeightedAliasIndex`], which samples in
+/// constant time regardless of the number of components.
+///
+/// This crate does not provide density functions or moments of
+/// distributions, so neither does `Mixture`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "D: Serialize, W: Serialize, W::Sampler: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "D: Deserialize<'de>, W: Deserialize<'de>, W::Sampler: Deserialize<'de>"
+    ))
+)]
+pub struct Mixture<D, W: AliasableWeight = f64> {
+    components: Vec<D>,
+    index: WeightedAliasIndex<W>,
+}
+
+impl<D, W: AliasableWeight> Mixture<D, W> {
+    /// Construct a mixture of `components` with the given `weights`, which
+    /// need not be normalised.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when `components` is empty, or its length
+    ///     differs from the number of weights.
+    /// -   [`Error::InvalidWeight`] and [`Error::InsufficientNonZero`] as for
+    ///     [`WeightedAliasIndex::new`].
+    pub fn new(components: Vec<D>, weights: Vec<W>) -> Result<Self, Error> {
+        if components.len() != weights.len() {
+            return Err(Error::InvalidInput);
+        }
+        let index = WeightedAliasIndex::new(weights)?;
+        Ok(Mixture { components, index })
+    }
+
+    /// Returns the components of the mixture.
+    pub fn components(&self) -> &[D] {
+        &self.components
+    }
+
+    /// Returns the weights of the components.
+    pub fn weights(&self) -> Vec<W> {
+        self.index.weights()
+    }
+}
+
+impl<T, D, W> Distribution<T> for Mixture<D, W>
+where
+    D: Distribution<T>,
+    W: AliasableWeight,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let i = self.index.sample(rng);
+        self.components[i].sample(rng)
+    }
+}
+
+impl<D, W> fmt::Debug for Mixture<D, W>
+where
+    D: fmt::Debug,
+    W: AliasableWeight + fmt::Debug,
+    Uniform<W>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mixture")
+            .field("components", &self.components)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<D, W> Clone for Mixture<D, W>
+where
+    D: Clone,
+    W: AliasableWeight,
+    Uniform<W>: Clone,
+{
+    fn clone(&self) -> Self {
+        Mixture {
+            components: self.components.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+/// A dyn-compatible version of [`Distribution`], implemented for all
+/// distributions.
+///
+/// [`Distribution::sample`] is generic over the RNG and thus cannot be
+/// called on a trait object. This trait is used by [`DynMixture`] to hold
+/// components of different types as `Box<dyn DynDistribution<T>>`.
+pub trait DynDistribution<T> {
+    /// Generate a random value of `T`, using `rng` as the source of
+    /// randomness.
+    fn sample_dyn(&self, rng: &mut dyn Rng) -> T;
+}
+
+impl<T, D: Distribution<T>> DynDistribution<T> for D {
+    fn sample_dyn(&self, rng: &mut dyn Rng) -> T {
+        self.sample(rng)
+    }
+}
+
+/// A finite [mixture distribution](https://en.wikipedia.org/wiki/Mixture_distribution)
+/// of boxed components of possibly different types.
+///
+/// This is a [`Mixture`] of `Box<dyn DynDistribution<T>>` components, e.g.
+/// to mix a normal and a heavy-tailed distribution. Sampling a component
+/// requires a dynamic dispatch, so [`Mixture`] should be preferred if the
+/// components are of the same type.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Cauchy, Distribution, DynMixture, Normal};
+///
+/// // A normal distribution contaminated with 5% Cauchy outliers
+/// let mixture = DynMixture::new(
+///     vec![
+///         Box::new(Normal::new(0.0, 1.0).unwrap()),
+///         Box::new(Cauchy::new(0.0, 1.0).unwrap()),
+///     ],
+///     vec![0.95, 0.05],
+/// )
+/// .unwrap();
+/// let v: f64 = mixture.sample(&mut rand::rng());
+/// println!("{} is from a contaminated normal distribution", v);
+/// ```
+pub struct DynMixture<'a, T, W: AliasableWeight = f64> {
+    mixture: Mixture<Box<dyn DynDistribution<T> + 'a>, W>,
+}
+
+impl<'a, T, W: AliasableWeight> DynMixture<'a, T, W> {
+    /// Construct a mixture of `components` with the given `weights`, which
+    /// need not be normalised.
+    ///
+    /// See [`Mixture::new`] for the error cases.
+    pub fn new(
+        components: Vec<Box<dyn DynDistribution<T> + 'a>>,
+        weights: Vec<W>,
+    ) -> Result<Self, Error> {
+        Ok(DynMixture {
+            mixture: Mixture::new(components, weights)?,
+        })
+    }
+
+    /// Returns the components of the mixture.
+    pub fn components(&self) -> &[Box<dyn DynDistribution<T> + 'a>] {
+        self.mixture.components()
+    }
+
+    /// Returns the weights of the components.
+    pub fn weights(&self) -> Vec<W> {
+        self.mixture.weights()
+    }
+}
+
+impl<T, W: AliasableWeight> Distribution<T> for DynMixture<'_, T, W> {
+    fn sample<R: Rng + ?Sized>(&self, mut rng: &mut R) -> T {
+        let i = self.mixture.index.sample(rng);
+        self.mixture.components[i].sample_dyn(&mut rng)
+    }
+}
+
+impl<T, W> fmt::Debug for DynMixture<'_, T, W>
+where
+    W: AliasableWeight + fmt::Debug,
+    Uniform<W>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DynMixture")
+            .field("components", &self.mixture.components.len())
+            .field("index", &self.mixture.index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Exp, Normal};
+    use alloc::vec;
+
+    #[test]
+    fn invalid() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Mixture::new(vec![normal; 2], vec![1.0]).unwrap_err(),
+            Error::InvalidInput
+        );
+        assert_eq!(
+            Mixture::<Normal<f64>>::new(vec![], vec![]).unwrap_err(),
+            Error::InvalidInput
+        );
+        assert_eq!(
+            Mixture::new(vec![normal; 2], vec![1.0, -1.0]).unwrap_err(),
+            Error::InvalidWeight
+        );
+        assert_eq!(
+            Mixture::new(vec![normal; 2], vec![0u32, 0]).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+        assert!(DynMixture::<f64>::new(vec![Box::new(normal)], vec![1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn sample() {
+        let components = vec![
+            Normal::new(-100.0, 1.0).unwrap(),
+            Normal::new(100.0, 1.0).unwrap(),
+        ];
+        let mixture = Mixture::new(components, vec![1u32, 3]).unwrap();
+        assert_eq!(mixture.weights(), vec![1, 3]);
+        assert_eq!(mixture.components().len(), 2);
+        let mut rng = crate::test::rng(371);
+        let mut positive = 0;
+        for _ in 0..1000 {
+            let x: f64 = mixture.sample(&mut rng);
+            assert!((x + 100.0).abs() < 10.0 || (x - 100.0).abs() < 10.0);
+            if x > 0.0 {
+                positive += 1;
+            }
+        }
+        assert!((700..800).contains(&positive));
+
+        // A single component with non-zero weight
+        let mixture = DynMixture::new(
+            vec![
+                Box::new(Exp::new(1.0).unwrap()),
+                Box::new(Normal::new(-10.0, 1.0).unwrap()),
+            ],
+            vec![1.0, 0.0],
+        )
+        .unwrap();
+        assert_eq!(mixture.components().len(), 2);
+        for _ in 0..100 {
+            assert!(mixture.sample(&mut rng) >= 0.0);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Dirichlet-multinomial distribution `DirichletMultinomial(n, α₁, α₂, ..., αₖ)`.
+
+#![cfg(feature = "alloc")]
+use crate::multi::{ConstMultiDistribution, MultiDistribution};
+use crate::{BetaBinomial, Distribution};
+use core::fmt;
+use rand::{Rng, RngExt};
+
+use alloc::{vec, vec::Vec};
+
+/// The [Dirichlet-multinomial distribution](https://en.wikipedia.org/wiki/Dirichlet-multinomial_distribution)
+/// `DirichletMultinomial(n, α₁, α₂, ..., αₖ)`.
+///
+/// This is the distribution of the counts of `k` categories after `n`
+/// trials, where the probabilities of the categories are drawn once from
+/// [`Dirichlet(α₁, α₂, ..., αₖ)`](crate::multi::Dirichlet) and then shared
+/// by all trials. Compared to the
+/// [`Multinomial`](crate::multi::Multinomial) distribution with
+/// probabilities `αᵢ / Σα`, the counts are overdispersed; the smaller
+/// `Σα`, the more so. The counts always sum to `n`. It is a multivariate
+/// generalization of the [`BetaBinomial`] distribution.
+///
+/// The concentration parameters are held in any `A: AsRef<[f64]>`, e.g. a
+/// `Vec<f64>` or an array `[f64; K]`. With an array, the dimension is known
+/// at compile time and [`ConstMultiDistribution`] and
+/// `Distribution<[u64; K]>` are implemented.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::multi::DirichletMultinomial;
+///
+/// let dm = DirichletMultinomial::new(100, vec![0.5, 1.0, 2.0]).unwrap();
+/// let counts: Vec<u64> = dm.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 100);
+/// ```
+///
+/// # Notes
+///
+/// For small `n`, samples are generated with the Pólya urn scheme: each
+/// trial picks category `i` with probability proportional to `αᵢ` plus the
+/// number of previous trials that picked `i`. Otherwise the count of each
+/// category is drawn from the [`BetaBinomial`] distribution of the trials not
+/// yet assigned, conditional on the previous categories. In both cases no
+/// probability vector is sampled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirichletMultinomial<A = Vec<f64>> {
+    n: u64,
+    alpha: A,
+    alpha_sum: f64,
+}
+
+/// Error type returned from [`DirichletMultinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `alpha.len() == 0`.
+    NoCategories,
+    /// An `alpha` is not positive, infinite or `nan`.
+    InvalidAlpha,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NoCategories => "no categories in Dirichlet-multinomial distribution",
+            Error::InvalidAlpha => {
+                "alpha is not positive and finite in Dirichlet-multinomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<A: AsRef<[f64]>> DirichletMultinomial<A> {
+    /// Construct a new `DirichletMultinomial` with `n` trials and the
+    /// concentration parameters `alpha`, which must be positive and finite.
+    pub fn new(n: u64, alpha: A) -> Result<DirichletMultinomial<A>, Error> {
+        let concentrations = alpha.as_ref();
+        if concentrations.is_empty() {
+            return Err(Error::NoCategories);
+        }
+        let mut alpha_sum = 0.0;
+        for &ai in concentrations {
+            if !(ai > 0.0 && ai.is_finite()) {
+                return Err(Error::InvalidAlpha);
+            }
+            alpha_sum += ai;
+        }
+        if !alpha_sum.is_finite() {
+            return Err(Error::InvalidAlpha);
+        }
+        Ok(DirichletMultinomial {
+            n,
+            alpha,
+            alpha_sum,
+        })
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the concentration parameters `alpha`.
+    pub fn alpha(&self) -> &[f64] {
+        self.alpha.as_ref()
+    }
+
+    fn sample_polya_urn<R: Rng + ?Sized>(&self, rng: &mut R, output: &mut [u64]) {
+        let alpha = self.alpha.as_ref();
+        output.fill(0);
+        for t in 0..self.n {
+            let mut u = rng.random::<f64>() * (self.alpha_sum + t as f64);
+            // Default to the last category in case of rounding
+            let mut chosen = output.len() - 1;
+            for (i, (&ai, &xi)) in alpha.iter().zip(output.iter()).enumerate() {
+                let weight = ai + xi as f64;
+                if u < weight {
+                    chosen = i;
+                    break;
+                }
+                u -= weight;
+            }
+            output[chosen] += 1;
+        }
+    }
+
+    fn sample_conditional<R: Rng + ?Sized>(&self, rng: &mut R, output: &mut [u64]) {
+        let (last, output) = output.split_last_mut().unwrap();
+        let mut remaining_n = self.n;
+        let mut remaining_alpha = self.alpha_sum;
+        for (x, &ai) in output.iter_mut().zip(self.alpha.as_ref()) {
+            remaining_alpha -= ai;
+            *x = if remaining_n == 0 {
+                0
+            } else if !(remaining_alpha > 0.0) {
+                // The remaining concentration is negligible (up to rounding)
+                remaining_n
+            } else {
+                BetaBinomial::new(remaining_n, ai, remaining_alpha)
+                    .unwrap()
+                    .sample(rng)
+            };
+            remaining_n -= *x;
+        }
+        *last = remaining_n;
+    }
+}
+
+impl<A: AsRef<[f64]>> MultiDistribution<u64> for DirichletMultinomial<A> {
+    #[inline]
+    fn sample_len(&self) -> usize {
+        self.alpha.as_ref().len()
+    }
+
+    fn sample_to_slice<R: Rng + ?Sized>(&self, rng: &mut R, output: &mut [u64]) {
+        assert_eq!(output.len(), self.sample_len());
+
+        // Each step of the urn scheme costs a scan over the categories, while
+        // the conditional method constructs a `BetaBinomial` per category.
+        const POLYA_URN_THRESHOLD: u64 = 32;
+
+        if self.n < POLYA_URN_THRESHOLD {
+            self.sample_polya_urn(rng, output);
+        } else {
+            self.sample_conditional(rng, output);
+        }
+    }
+}
+
+impl<const K: usize> ConstMultiDistribution<u64> for DirichletMultinomial<[f64; K]> {
+    const SAMPLE_LEN: usize = K;
+}
+
+impl<A: AsRef<[f64]>> Distribution<Vec<u64>> for DirichletMultinomial<A> {
+    distribution_impl!(u64);
+}
+
+impl<const K: usize> Distribution<[u64; K]> for DirichletMultinomial<[f64; K]> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; K] {
+        let mut buf = [0; K];
+        self.sample_to_slice(rng, &mut buf);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dirichlet_multinomial_invalid_parameters() {
+        assert_eq!(
+            DirichletMultinomial::new(10, Vec::new()),
+            Err(Error::NoCategories)
+        );
+        assert_eq!(
+            DirichletMultinomial::new(10, [1.0, 0.0]),
+            Err(Error::InvalidAlpha)
+        );
+        assert_eq!(
+            DirichletMultinomial::new(10, [1.0, f64::NAN]),
+            Err(Error::InvalidAlpha)
+        );
+        assert_eq!(
+            DirichletMultinomial::new(10, [f64::MAX, f64::MAX]),
+            Err(Error::InvalidAlpha)
+        );
+        assert!(DirichletMultinomial::new(10, [1.0]).is_ok());
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial_moments() {
+        // covers both the Pólya urn and the conditional method
+        let alpha = [0.5, 1.5, 3.0];
+        let alpha_sum = 5.0;
+        for (seed, n) in [10, 200].into_iter().enumerate() {
+            let d = DirichletMultinomial::new(n, alpha).unwrap();
+            let mut rng = crate::test::rng(430 + seed as u64);
+            let samples = 10000;
+            let mut sums = [0.0; 3];
+            let mut sums_sq = [0.0; 3];
+            for _ in 0..samples {
+                let x: [u64; 3] = d.sample(&mut rng);
+                assert_eq!(x.iter().sum::<u64>(), n);
+                for i in 0..3 {
+                    sums[i] += x[i] as f64;
+                    sums_sq[i] += (x[i] * x[i]) as f64;
+                }
+            }
+            let n = n as f64;
+            for i in 0..3 {
+                let p = alpha[i] / alpha_sum;
+                let expected_mean = n * p;
+                let expected_variance = n * p * (1.0 - p) * (n + alpha_sum) / (1.0 + alpha_sum);
+                let mean = sums[i] / samples as f64;
+                let variance = sums_sq[i] / samples as f64 - mean * mean;
+                assert!((mean - expected_mean).abs() < expected_mean / 20.0);
+                assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial_large_n() {
+        // the first category has a small mean, which is sampled by inversion
+        let n = 1e14;
+        let d = DirichletMultinomial::new(n as u64, [2.0, n / 10.0, n / 10.0]).unwrap();
+        let mut rng = crate::test::rng(432);
+        let samples = 10000;
+        let mut sum = 0.0;
+        for _ in 0..samples {
+            let x: [u64; 3] = d.sample(&mut rng);
+            assert_eq!(x.iter().sum::<u64>(), n as u64);
+            sum += x[0] as f64;
+        }
+        let expected_mean = n * 2.0 / (2.0 + n / 5.0);
+        assert!((sum / samples as f64 - expected_mean).abs() < expected_mean / 20.0);
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial_const() {
+        let d = DirichletMultinomial::new(7, [1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(
+            <DirichletMultinomial<[f64; 3]> as ConstMultiDistribution<u64>>::SAMPLE_LEN,
+            3
+        );
+        assert_eq!(d.sample_len(), 3);
+        assert_eq!(d.alpha(), &[1.0, 1.0, 1.0]);
+        assert_eq!(d.n(), 7);
+    }
+
+    #[test]
+    fn dirichlet_multinomial_distributions_can_be_compared() {
+        assert_eq!(
+            DirichletMultinomial::new(5, vec![1.0, 2.0]),
+            DirichletMultinomial::new(5, vec![1.0, 2.0])
+        );
+    }
+}
@@ -70,5 +70,13 @@ macro_rules! const_distribution_impl {
 }
 
 pub use dirichlet::Dirichlet;
+pub use dirichlet_multinomial::{DirichletMultinomial, Error as DirichletMultinomialError};
+pub use multinomial::{Error as MultinomialError, Multinomial};
+pub use multivariate_hypergeometric::{
+    Error as MultivariateHypergeometricError, MultivariateHypergeometric,
+};
 
 mod dirichlet;
+mod dirichlet_multinomial;
+mod multinomial;
+mod multivariate_hypergeometric;
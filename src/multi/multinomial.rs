@@ -0,0 +1,262 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multinomial distribution `Multinomial(n, p₁, p₂, ..., pₖ)`.
+
+#![cfg(feature = "alloc")]
+use crate::multi::{ConstMultiDistribution, MultiDistribution};
+use crate::{Binomial, Distribution};
+use core::fmt;
+use rand::Rng;
+
+use alloc::{vec, vec::Vec};
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution)
+/// `Multinomial(n, p₁, p₂, ..., pₖ)`.
+///
+/// This is the distribution of the number of outcomes in each of `k`
+/// categories after `n` independent trials, where each trial results in
+/// category `i` with probability `pᵢ`. The counts always sum to `n`. It
+/// is a multivariate generalization of the [`Binomial`] distribution.
+///
+/// The probabilities are held in any `P: AsRef<[f64]>`, e.g. a `Vec<f64>`
+/// or an array `[f64; K]`. With an array, the dimension is known at compile
+/// time and [`ConstMultiDistribution`] and `Distribution<[u64; K]>` are
+/// implemented.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::multi::Multinomial;
+///
+/// let multinomial = Multinomial::new(100, vec![0.2, 0.3, 0.5]).unwrap();
+/// let counts: Vec<u64> = multinomial.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 100);
+///
+/// // With a fixed number of categories
+/// let dice = Multinomial::new(10, [1.0 / 6.0; 6]).unwrap();
+/// let counts: [u64; 6] = dice.sample(&mut rand::rng());
+/// println!("{:?} are the counts of 10 rolls of a die", counts);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated by conditional [`Binomial`] sampling: the count
+/// of each category is drawn from the binomial distribution of the trials
+/// not yet assigned, with the probability of that category conditional on
+/// not being one of the previous categories. The cost is thus proportional
+/// to the number of categories and independent of `n`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multinomial<P = Vec<f64>> {
+    n: u64,
+    p: P,
+    total: f64,
+}
+
+/// Error type returned from [`Multinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `p.len() == 0`.
+    NoCategories,
+    /// A probability is negative, infinite or `nan`, or their sum is
+    /// infinite.
+    InvalidProbability,
+    /// All probabilities are zero.
+    AllProbabilitiesZero,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NoCategories => "no categories in multinomial distribution",
+            Error::InvalidProbability => {
+                "a probability is negative, infinite or nan in multinomial distribution"
+            }
+            Error::AllProbabilitiesZero => "all probabilities are zero in multinomial distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<P: AsRef<[f64]>> Multinomial<P> {
+    /// Construct a new `Multinomial` with `n` trials and the probabilities
+    /// `p` of the categories.
+    ///
+    /// The probabilities must be non-negative and finite, and not all zero.
+    /// They are normalized by their sum, which need not be one but must be
+    /// finite.
+    pub fn new(n: u64, p: P) -> Result<Multinomial<P>, Error> {
+        let probabilities = p.as_ref();
+        if probabilities.is_empty() {
+            return Err(Error::NoCategories);
+        }
+        let mut total = 0.0;
+        for &pi in probabilities {
+            if !(pi >= 0.0 && pi.is_finite()) {
+                return Err(Error::InvalidProbability);
+            }
+            total += pi;
+        }
+        if !total.is_finite() {
+            return Err(Error::InvalidProbability);
+        }
+        if !(total > 0.0) {
+            return Err(Error::AllProbabilitiesZero);
+        }
+        Ok(Multinomial { n, p, total })
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the probabilities of the categories, as passed to
+    /// [`Multinomial::new`].
+    pub fn p(&self) -> &[f64] {
+        self.p.as_ref()
+    }
+}
+
+impl<P: AsRef<[f64]>> MultiDistribution<u64> for Multinomial<P> {
+    #[inline]
+    fn sample_len(&self) -> usize {
+        self.p.as_ref().len()
+    }
+
+    fn sample_to_slice<R: Rng + ?Sized>(&self, rng: &mut R, output: &mut [u64]) {
+        assert_eq!(output.len(), self.sample_len());
+
+        let (last, output) = output.split_last_mut().unwrap();
+        let mut remaining_n = self.n;
+        let mut remaining_p = self.total;
+        for (x, &pi) in output.iter_mut().zip(self.p.as_ref()) {
+            if remaining_n == 0 {
+                *x = 0;
+                continue;
+            }
+            // Rounding may leave remaining_p slightly below pi
+            let p = if pi < remaining_p {
+                pi / remaining_p
+            } else {
+                1.0
+            };
+            *x = Binomial::new(remaining_n, p).unwrap().sample(rng);
+            remaining_n -= *x;
+            remaining_p -= pi;
+        }
+        *last = remaining_n;
+    }
+}
+
+impl<const K: usize> ConstMultiDistribution<u64> for Multinomial<[f64; K]> {
+    const SAMPLE_LEN: usize = K;
+}
+
+impl<P: AsRef<[f64]>> Distribution<Vec<u64>> for Multinomial<P> {
+    distribution_impl!(u64);
+}
+
+impl<const K: usize> Distribution<[u64; K]> for Multinomial<[f64; K]> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; K] {
+        let mut buf = [0; K];
+        self.sample_to_slice(rng, &mut buf);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multinomial_invalid_parameters() {
+        assert_eq!(Multinomial::new(10, Vec::new()), Err(Error::NoCategories));
+        assert_eq!(
+            Multinomial::new(10, [0.5, -0.1]),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.5, f64::NAN]),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.5, f64::INFINITY]),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            Multinomial::new(10, [f64::MAX, f64::MAX]),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.0, 0.0]),
+            Err(Error::AllProbabilitiesZero)
+        );
+    }
+
+    #[test]
+    fn test_multinomial_sum_and_zero_categories() {
+        let d = Multinomial::new(1000, vec![0.0, 2.0, 0.0, 1.0, 0.0]).unwrap();
+        let mut rng = crate::test::rng(411);
+        for _ in 0..100 {
+            let x: Vec<u64> = d.sample(&mut rng);
+            assert_eq!(x.iter().sum::<u64>(), 1000);
+            assert_eq!((x[0], x[2], x[4]), (0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_multinomial_means() {
+        let p = [0.1, 0.2, 0.3, 0.4];
+        let d = Multinomial::new(50, p).unwrap();
+        let mut rng = crate::test::rng(412);
+        let mut sums = [0.0; 4];
+        let n = 10000;
+        for _ in 0..n {
+            let x: [u64; 4] = d.sample(&mut rng);
+            for (s, x) in sums.iter_mut().zip(x) {
+                *s += x as f64;
+            }
+        }
+        for (s, p) in sums.iter().zip(p) {
+            assert!((s / n as f64 - 50.0 * p).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_multinomial_const() {
+        let d = Multinomial::new(7, [1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(
+            <Multinomial<[f64; 3]> as ConstMultiDistribution<u64>>::SAMPLE_LEN,
+            3
+        );
+        assert_eq!(d.sample_len(), 3);
+        assert_eq!(d.p(), &[1.0, 1.0, 1.0]);
+        assert_eq!(d.n(), 7);
+    }
+
+    #[test]
+    fn test_multinomial_zero_trials() {
+        let d = Multinomial::new(0, [0.5, 0.5]).unwrap();
+        let mut rng = crate::test::VoidRng;
+        let x: [u64; 2] = d.sample(&mut rng);
+        assert_eq!(x, [0, 0]);
+    }
+
+    #[test]
+    fn multinomial_distributions_can_be_compared() {
+        assert_eq!(
+            Multinomial::new(5, vec![1.0, 2.0]),
+            Multinomial::new(5, vec![1.0, 2.0])
+        );
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate hypergeometric distribution.
+
+#![cfg(feature = "alloc")]
+use crate::multi::{ConstMultiDistribution, MultiDistribution};
+use crate::{Distribution, Hypergeometric};
+use core::fmt;
+use rand::Rng;
+
+use alloc::{vec, vec::Vec};
+
+/// The [multivariate hypergeometric distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution#Multivariate_hypergeometric_distribution)
+/// `MultivariateHypergeometric(K₁, K₂, ..., Kₖ, n)`.
+///
+/// This is the distribution of the number of items of each of `k`
+/// categories in a sample of size `n` drawn without replacement from a
+/// population of `N = K₁ + K₂ + ... + Kₖ` items, of which `Kᵢ` belong to
+/// category `i`. The counts always sum to `n`. It is a multivariate
+/// generalization of the [`Hypergeometric`] distribution.
+///
+/// The category counts are held in any `C: AsRef<[u64]>`, e.g. a `Vec<u64>`
+/// or an array `[u64; K]`. With an array, the dimension is known at compile
+/// time and [`ConstMultiDistribution`] and `Distribution<[u64; K]>` are
+/// implemented.
+///
+/// See [`Multinomial`](crate::multi::Multinomial) for the analogous
+/// distribution for sampling with replacement.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand_distr::multi::MultivariateHypergeometric;
+///
+/// // An urn with 10 red, 20 green and 30 blue balls, from which 12 are drawn
+/// let urn = MultivariateHypergeometric::new([10, 20, 30], 12).unwrap();
+/// let [red, green, blue]: [u64; 3] = urn.sample(&mut rand::rng());
+/// assert_eq!(red + green + blue, 12);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated by conditional [`Hypergeometric`] sampling: the
+/// count of each category is drawn from the hypergeometric distribution of
+/// the items not yet drawn among the population of the remaining categories.
+/// The cost is thus proportional to the number of categories.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultivariateHypergeometric<C = Vec<u64>> {
+    counts: C,
+    total: u64,
+    sample_size: u64,
+}
+
+/// Error type returned from [`MultivariateHypergeometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `counts.len() == 0`.
+    NoCategories,
+    /// The total population size overflows `u64`.
+    PopulationTooLarge,
+    /// `sample_size` is larger than the total population size.
+    SampleSizeTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NoCategories => "no categories in multivariate hypergeometric distribution",
+            Error::PopulationTooLarge => {
+                "total population size overflows u64 in multivariate hypergeometric distribution"
+            }
+            Error::SampleSizeTooLarge => {
+                "sample_size > total population size in multivariate hypergeometric distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<C: AsRef<[u64]>> MultivariateHypergeometric<C> {
+    /// Construct a new `MultivariateHypergeometric` with the number of items
+    /// `counts` of each category and the `sample_size`.
+    pub fn new(counts: C, sample_size: u64) -> Result<MultivariateHypergeometric<C>, Error> {
+        let category_counts = counts.as_ref();
+        if category_counts.is_empty() {
+            return Err(Error::NoCategories);
+        }
+        let mut total: u64 = 0;
+        for &count in category_counts {
+            total = total.checked_add(count).ok_or(Error::PopulationTooLarge)?;
+        }
+        if sample_size > total {
+            return Err(Error::SampleSizeTooLarge);
+        }
+        Ok(MultivariateHypergeometric {
+            counts,
+            total,
+            sample_size,
+        })
+    }
+
+    /// Returns the number of items of each category.
+    pub fn counts(&self) -> &[u64] {
+        self.counts.as_ref()
+    }
+
+    /// Returns the total population size.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the sample size `n`.
+    pub fn sample_size(&self) -> u64 {
+        self.sample_size
+    }
+}
+
+impl<C: AsRef<[u64]>> MultiDistribution<u64> for MultivariateHypergeometric<C> {
+    #[inline]
+    fn sample_len(&self) -> usize {
+        self.counts.as_ref().len()
+    }
+
+    fn sample_to_slice<R: Rng + ?Sized>(&self, rng: &mut R, output: &mut [u64]) {
+        assert_eq!(output.len(), self.sample_len());
+
+        let (last, output) = output.split_last_mut().unwrap();
+        let mut remaining_total = self.total;
+        let mut remaining_sample = self.sample_size;
+        for (x, &count) in output.iter_mut().zip(self.counts.as_ref()) {
+            *x = if remaining_sample == 0 || count == 0 {
+                0
+            } else {
+                // The parameters are valid by construction
+                Hypergeometric::new(remaining_total, count, remaining_sample)
+                    .unwrap()
+                    .sample(rng)
+            };
+            remaining_total -= count;
+            remaining_sample -= *x;
+        }
+        *last = remaining_sample;
+    }
+}
+
+impl<const K: usize> ConstMultiDistribution<u64> for MultivariateHypergeometric<[u64; K]> {
+    const SAMPLE_LEN: usize = K;
+}
+
+impl<C: AsRef<[u64]>> Distribution<Vec<u64>> for MultivariateHypergeometric<C> {
+    distribution_impl!(u64);
+}
+
+impl<const K: usize> Distribution<[u64; K]> for MultivariateHypergeometric<[u64; K]> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; K] {
+        let mut buf = [0; K];
+        self.sample_to_slice(rng, &mut buf);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multivariate_hypergeometric_invalid_parameters() {
+        assert_eq!(
+            MultivariateHypergeometric::new(Vec::new(), 0),
+            Err(Error::NoCategories)
+        );
+        assert_eq!(
+            MultivariateHypergeometric::new([u64::MAX, 1], 1),
+            Err(Error::PopulationTooLarge)
+        );
+        assert_eq!(
+            MultivariateHypergeometric::new([3, 4], 8),
+            Err(Error::SampleSizeTooLarge)
+        );
+        assert!(MultivariateHypergeometric::new([u64::MAX - 1, 1], 1).is_ok());
+        assert!(MultivariateHypergeometric::new([3, 4], 7).is_ok());
+    }
+
+    #[test]
+    fn test_multivariate_hypergeometric_bounds() {
+        let counts = vec![5, 0, 40, 1, 7];
+        let d = MultivariateHypergeometric::new(counts.clone(), 30).unwrap();
+        let mut rng = crate::test::rng(421);
+        for _ in 0..100 {
+            let x: Vec<u64> = d.sample(&mut rng);
+            assert_eq!(x.iter().sum::<u64>(), 30);
+            assert!(x.iter().zip(&counts).all(|(x, c)| x <= c));
+        }
+    }
+
+    #[test]
+    fn test_multivariate_hypergeometric_whole_population() {
+        let d = MultivariateHypergeometric::new([3, 0, 5, 2], 10).unwrap();
+        let mut rng = crate::test::rng(422);
+        let x: [u64; 4] = d.sample(&mut rng);
+        assert_eq!(x, [3, 0, 5, 2]);
+    }
+
+    #[test]
+    fn test_multivariate_hypergeometric_means() {
+        let counts = [10, 20, 30, 40];
+        let d = MultivariateHypergeometric::new(counts, 25).unwrap();
+        let mut rng = crate::test::rng(423);
+        let mut sums = [0.0; 4];
+        let n = 10000;
+        for _ in 0..n {
+            let x: [u64; 4] = d.sample(&mut rng);
+            for (s, x) in sums.iter_mut().zip(x) {
+                *s += x as f64;
+            }
+        }
+        for (s, c) in sums.iter().zip(counts) {
+            assert!((s / n as f64 - 25.0 * c as f64 / 100.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_multivariate_hypergeometric_const() {
+        let d = MultivariateHypergeometric::new([1, 2, 3], 4).unwrap();
+        assert_eq!(
+            <MultivariateHypergeometric<[u64; 3]> as ConstMultiDistribution<u64>>::SAMPLE_LEN,
+            3
+        );
+        assert_eq!(d.sample_len(), 3);
+        assert_eq!(d.counts(), &[1, 2, 3]);
+        assert_eq!(d.total(), 6);
+        assert_eq!(d.sample_size(), 4);
+    }
+
+    #[test]
+    fn multivariate_hypergeometric_distributions_can_be_compared() {
+        assert_eq!(
+            MultivariateHypergeometric::new(vec![1, 2], 2),
+            MultivariateHypergeometric::new(vec![1, 2], 2)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Nakagami distribution `Nakagami(m, Ω)`.
+
+use crate::{Distribution, Exp1, Gamma, GammaError, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [Nakagami distribution](https://en.wikipedia.org/wiki/Nakagami_distribution) `Nakagami(m, Ω)`.
+///
+/// This is a continuous probability distribution with shape parameter
+/// `m >= 1/2` and spread parameter `Ω > 0`, used to model the amplitude of
+/// fading radio signals. It is the distribution of `sqrt(Y)` where
+/// `Y ~ Gamma(m, Ω / m)`, so that `Ω` is the mean of the squared samples.
+///
+/// For `m = 1/2` this is the [`HalfNormal`](crate::HalfNormal)
+/// distribution, and for `m = 1` a Rayleigh distribution.
+///
+/// # Density function
+///
+/// `f(x) = 2 m^m / (Γ(m) Ω^m) * x^(2m - 1) * exp(-m x² / Ω)` for `x >= 0`,
+/// where `Γ` is the [gamma function](https://en.wikipedia.org/wiki/Gamma_function).
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Nakagami, Distribution};
+///
+/// let nakagami = Nakagami::new(2.0, 1.0).unwrap();
+/// let v = nakagami.sample(&mut rand::rng());
+/// println!("{} is from a Nakagami(2, 1) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Nakagami<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    gamma: Gamma<F>,
+}
+
+/// Error type returned from [`Nakagami::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `m < 0.5`, `m = ∞` or `nan`.
+    ShapeTooSmall,
+    /// `omega <= 0`, `omega = ∞` or `nan`.
+    SpreadNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ShapeTooSmall => "shape m < 0.5 or is infinite or NaN in Nakagami distribution",
+            Error::SpreadNotPositive => {
+                "spread omega is not positive and finite in Nakagami distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> Nakagami<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `Nakagami` distribution with shape `m` and spread
+    /// `omega` (`Ω`).
+    pub fn new(m: F, omega: F) -> Result<Nakagami<F>, Error> {
+        if !(m >= F::from(0.5).unwrap()) || m.is_infinite() {
+            return Err(Error::ShapeTooSmall);
+        }
+        if !(omega > F::zero()) || omega.is_infinite() {
+            return Err(Error::SpreadNotPositive);
+        }
+        // The scale omega / m can underflow to zero.
+        let gamma = Gamma::new(m, omega / m).map_err(|e| match e {
+            GammaError::ShapeTooSmall => Error::ShapeTooSmall,
+            GammaError::ScaleTooSmall | GammaError::ScaleTooLarge => Error::SpreadNotPositive,
+        })?;
+        Ok(Nakagami { gamma })
+    }
+}
+
+impl<F> Distribution<F> for Nakagami<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.gamma.sample(rng).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nakagami() {
+        let d = Nakagami::new(2.0, 1.0).unwrap();
+        let mut rng = crate::test::rng(241);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_nakagami_invalid() {
+        assert_eq!(Nakagami::new(0.4, 1.0), Err(Error::ShapeTooSmall));
+        assert_eq!(Nakagami::new(f64::NAN, 1.0), Err(Error::ShapeTooSmall));
+        assert_eq!(Nakagami::new(f64::INFINITY, 1.0), Err(Error::ShapeTooSmall));
+        assert_eq!(Nakagami::new(1e300, 1e-300), Err(Error::SpreadNotPositive));
+        assert_eq!(Nakagami::new(1.0, 0.0), Err(Error::SpreadNotPositive));
+        assert_eq!(
+            Nakagami::new(1.0, f64::INFINITY),
+            Err(Error::SpreadNotPositive)
+        );
+    }
+
+    #[test]
+    fn nakagami_distributions_can_be_compared() {
+        assert_eq!(Nakagami::new(1.0, 2.0), Nakagami::new(1.0, 2.0));
+    }
+}
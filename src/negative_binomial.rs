@@ -0,0 +1,267 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative binomial distribution `NegativeBinomial(r, p)`.
+
+use crate::{Distribution, Gamma, Geometric, Poisson};
+use core::fmt;
+use rand::Rng;
+
+/// The [negative binomial distribution](https://en.wikipedia.org/wiki/Negative_binomial_distribution)
+/// `NegativeBinomial(r, p)`.
+///
+/// This is the distribution of the number of failures before the `r`-th
+/// success in a series of [`Bernoulli`](crate::Bernoulli) trials, where the
+/// probability of success on each trial is `p`. The number of successes `r`
+/// may be any positive real number, in which case the distribution is
+/// the Poisson distribution whose rate is `Gamma(r, (1 - p) / p)`
+/// distributed. This makes it a common model of overdispersed count data,
+/// see [`NegativeBinomial::from_mean_dispersion`].
+///
+/// For `r = 1` this is the [`Geometric`] distribution.
+///
+/// # Density function
+///
+/// `f(k) = Γ(k + r) / (k! Γ(r)) (1 - p)^k p^r` for `k >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, NegativeBinomial};
+///
+/// let nb = NegativeBinomial::new(2.5, 0.3).unwrap();
+/// let v = nb.sample(&mut rand::rng());
+/// println!("{} is from a negative binomial distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated by drawing a rate from the gamma distribution and
+/// then a count from the [`Poisson`] distribution with that rate. For
+/// `r = 1` the [`Geometric`] sampler is used instead.
+///
+/// When the sampled rate exceeds [`Poisson::MAX_LAMBDA`], which can only
+/// happen for extremely small `p`, the sample saturates to `u64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Constant,
+    Geometric(Geometric),
+    GammaPoisson(Gamma<f64>),
+}
+
+/// Error type returned from [`NegativeBinomial::new`] and
+/// [`NegativeBinomial::from_mean_dispersion`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `r <= 0`, `r = ∞` or `nan`.
+    RNotPositive,
+    /// `p <= 0`, `p > 1` or `nan`.
+    InvalidProbability,
+    /// `mean <= 0`, `mean = ∞` or `nan`.
+    MeanNotPositive,
+    /// `dispersion <= 0`, `dispersion = ∞` or `nan`.
+    DispersionNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::RNotPositive => "r is not positive and finite in negative binomial distribution",
+            Error::InvalidProbability => {
+                "p is NaN or outside the interval (0, 1] in negative binomial distribution"
+            }
+            Error::MeanNotPositive => {
+                "mean is not positive and finite in negative binomial distribution"
+            }
+            Error::DispersionNotPositive => {
+                "dispersion is not positive and finite in negative binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl NegativeBinomial {
+    /// Construct a new `NegativeBinomial` distribution with the number of
+    /// successes `r` and the probability of success `p`.
+    ///
+    /// `r` need not be an integer.
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, Error> {
+        if !(r > 0.0 && r.is_finite()) {
+            return Err(Error::RNotPositive);
+        }
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(Error::InvalidProbability);
+        }
+
+        let method = if p == 1.0 {
+            Method::Constant
+        } else if r == 1.0 {
+            Method::Geometric(Geometric::new(p).unwrap())
+        } else {
+            let scale = (1.0 - p) / p;
+            if !scale.is_finite() {
+                // p is subnormal
+                return Err(Error::InvalidProbability);
+            }
+            Method::GammaPoisson(Gamma::new(r, scale).unwrap())
+        };
+
+        Ok(NegativeBinomial { r, p, method })
+    }
+
+    /// Construct a new `NegativeBinomial` distribution with the given
+    /// `mean` and `dispersion`.
+    ///
+    /// This is the NB2 parameterisation of regression models, where the
+    /// variance is `mean + dispersion * mean^2`. It corresponds to
+    /// `r = 1 / dispersion` and `p = 1 / (1 + dispersion * mean)`. As the
+    /// dispersion approaches zero, the distribution approaches
+    /// `Poisson(mean)`.
+    pub fn from_mean_dispersion(mean: f64, dispersion: f64) -> Result<NegativeBinomial, Error> {
+        if !(mean > 0.0 && mean.is_finite()) {
+            return Err(Error::MeanNotPositive);
+        }
+        if !(dispersion > 0.0 && dispersion.is_finite()) {
+            return Err(Error::DispersionNotPositive);
+        }
+        NegativeBinomial::new(1.0 / dispersion, 1.0 / (1.0 + dispersion * mean))
+    }
+
+    /// Returns the number of successes `r`.
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    /// Returns the probability of success `p`.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns the mean `r (1 - p) / p`.
+    pub fn mean(&self) -> f64 {
+        self.r * (1.0 - self.p) / self.p
+    }
+}
+
+impl Distribution<u64> for NegativeBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match &self.method {
+            Method::Constant => 0,
+            Method::Geometric(geometric) => geometric.sample(rng),
+            Method::GammaPoisson(gamma) => {
+                let lambda = gamma.sample(rng);
+                if !(lambda > 0.0) {
+                    // possible for very small r
+                    return 0;
+                }
+                match Poisson::new(lambda) {
+                    Ok(poisson) => {
+                        let k: f64 = poisson.sample(rng);
+                        k as u64
+                    }
+                    Err(_) => u64::MAX,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(NegativeBinomial::new(0.0, 0.5), Err(Error::RNotPositive));
+        assert_eq!(
+            NegativeBinomial::new(f64::NAN, 0.5),
+            Err(Error::RNotPositive)
+        );
+        assert_eq!(
+            NegativeBinomial::new(f64::INFINITY, 0.5),
+            Err(Error::RNotPositive)
+        );
+        assert_eq!(
+            NegativeBinomial::new(2.0, 0.0),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            NegativeBinomial::new(2.0, 1.5),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            NegativeBinomial::new(2.0, f64::NAN),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            NegativeBinomial::from_mean_dispersion(-1.0, 0.5),
+            Err(Error::MeanNotPositive)
+        );
+        assert_eq!(
+            NegativeBinomial::from_mean_dispersion(1.0, 0.0),
+            Err(Error::DispersionNotPositive)
+        );
+    }
+
+    #[test]
+    fn test_p_one() {
+        let nb = NegativeBinomial::new(3.0, 1.0).unwrap();
+        let mut rng = crate::test::VoidRng;
+        assert_eq!(nb.sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn test_from_mean_dispersion() {
+        let nb = NegativeBinomial::from_mean_dispersion(4.0, 0.25).unwrap();
+        assert_almost_eq!(nb.r(), 4.0, 1e-15);
+        assert_almost_eq!(nb.p(), 0.5, 1e-15);
+        assert_almost_eq!(nb.mean(), 4.0, 1e-15);
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(391);
+        for (r, p) in [(1.0, 0.3), (2.5, 0.4), (0.5, 0.1)] {
+            let nb = NegativeBinomial::new(r, p).unwrap();
+            let expected_mean = r * (1.0 - p) / p;
+            let expected_variance = expected_mean / p;
+
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = nb.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < expected_mean / 20.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 5.0);
+        }
+    }
+
+    #[test]
+    fn negative_binomial_distributions_can_be_compared() {
+        assert_eq!(
+            NegativeBinomial::new(2.0, 0.3),
+            NegativeBinomial::new(2.0, 0.3)
+        );
+    }
+}
@@ -0,0 +1,442 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Piecewise-constant and piecewise-linear distributions.
+
+use crate::weighted::{self, AliasableWeight, WeightedAliasIndex};
+use crate::{Distribution, StandardUniform, Uniform};
+use alloc::vec::Vec;
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Error type returned from [`PiecewiseConstant::new`] and
+/// [`PiecewiseLinear::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Fewer than two breakpoints, or more than `u32::MAX` intervals.
+    InvalidBreakpointCount,
+    /// The number of densities does not match the number of breakpoints.
+    LengthMismatch,
+    /// The breakpoints are not finite and strictly increasing.
+    BreakpointsNotSorted,
+    /// A density is negative, infinite or `nan`, or the probability of an
+    /// interval is not representable.
+    InvalidDensity,
+    /// The densities are zero on all intervals.
+    AllDensitiesZero,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidBreakpointCount => {
+                "number of breakpoints is invalid in piecewise distribution"
+            }
+            Error::LengthMismatch => {
+                "number of densities does not match breakpoints in piecewise distribution"
+            }
+            Error::BreakpointsNotSorted => {
+                "breakpoints are not finite and strictly increasing in piecewise distribution"
+            }
+            Error::InvalidDensity => {
+                "density is negative, infinite or NaN in piecewise distribution"
+            }
+            Error::AllDensitiesZero => "all densities are zero in piecewise distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Validate `breakpoints` and `densities`, and build the alias table for the
+/// intervals from their (unnormalised) probabilities `weight(i, width)`.
+fn interval_index<F>(
+    breakpoints: &[F],
+    densities: &[F],
+    num_densities: usize,
+    weight: impl Fn(usize, F) -> F,
+) -> Result<WeightedAliasIndex<F>, Error>
+where
+    F: Float + AliasableWeight,
+{
+    if breakpoints.len() < 2 {
+        return Err(Error::InvalidBreakpointCount);
+    }
+    if densities.len() != num_densities {
+        return Err(Error::LengthMismatch);
+    }
+    if !breakpoints.iter().all(|x| x.is_finite()) || breakpoints.windows(2).any(|w| !(w[0] < w[1]))
+    {
+        return Err(Error::BreakpointsNotSorted);
+    }
+    if densities
+        .iter()
+        .any(|d| !(*d >= F::zero()) || d.is_infinite())
+    {
+        return Err(Error::InvalidDensity);
+    }
+
+    let weights = breakpoints
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| weight(i, w[1] - w[0]))
+        .collect::<Vec<F>>();
+    if weights.iter().any(|w| w.is_infinite()) {
+        return Err(Error::InvalidDensity);
+    }
+    WeightedAliasIndex::new(weights).map_err(|e| match e {
+        weighted::Error::InvalidInput => Error::InvalidBreakpointCount,
+        weighted::Error::InsufficientNonZero => Error::AllDensitiesZero,
+        _ => Error::InvalidDensity,
+    })
+}
+
+/// A piecewise-constant distribution over the intervals given by
+/// breakpoints.
+///
+/// Given breakpoints `b[0] < b[1] < ... < b[n]` and densities
+/// `d[0], ..., d[n - 1]`, this is the distribution on `[b[0], b[n])` with
+/// density proportional to `d[i]` on the interval `[b[i], b[i + 1])`. This is
+/// the equivalent of C++'s `std::piecewise_constant_distribution`.
+///
+/// # Density function
+///
+/// `f(x) = d[i] / S` for `b[i] <= x < b[i + 1]`, where
+/// `S = Σ d[i] * (b[i + 1] - b[i])`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, PiecewiseConstant};
+///
+/// // Half of the samples are in [0, 1), the other half in [1, 3)
+/// let d = PiecewiseConstant::new(vec![0.0, 1.0, 3.0], vec![2.0, 1.0]).unwrap();
+/// let v = d.sample(&mut rand::rng());
+/// println!("{} is from a piecewise-constant distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// The interval is selected with a [`WeightedAliasIndex`], and the sample is
+/// then uniform within that interval.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "F: Serialize, F::Sampler: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: Deserialize<'de>, F::Sampler: Deserialize<'de>"))
+)]
+pub struct PiecewiseConstant<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    breakpoints: Vec<F>,
+    densities: Vec<F>,
+    index: WeightedAliasIndex<F>,
+}
+
+impl<F> PiecewiseConstant<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    /// Construct a new `PiecewiseConstant` distribution from `n + 1`
+    /// strictly increasing `breakpoints` and `n` non-negative `densities`,
+    /// which need not be normalised.
+    pub fn new(breakpoints: Vec<F>, densities: Vec<F>) -> Result<PiecewiseConstant<F>, Error> {
+        let num_densities = breakpoints.len().saturating_sub(1);
+        let index = interval_index(&breakpoints, &densities, num_densities, |i, width| {
+            densities[i] * width
+        })?;
+        Ok(PiecewiseConstant {
+            breakpoints,
+            densities,
+            index,
+        })
+    }
+
+    /// Returns the breakpoints of the distribution.
+    pub fn breakpoints(&self) -> &[F] {
+        &self.breakpoints
+    }
+
+    /// Returns the (unnormalised) densities on the intervals.
+    pub fn densities(&self) -> &[F] {
+        &self.densities
+    }
+}
+
+impl<F> fmt::Debug for PiecewiseConstant<F>
+where
+    F: Float + AliasableWeight + fmt::Debug,
+    Uniform<F>: fmt::Debug,
+    StandardUniform: Distribution<F>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PiecewiseConstant")
+            .field("breakpoints", &self.breakpoints)
+            .field("densities", &self.densities)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<F> Clone for PiecewiseConstant<F>
+where
+    F: Float + AliasableWeight,
+    Uniform<F>: Clone,
+    StandardUniform: Distribution<F>,
+{
+    fn clone(&self) -> Self {
+        PiecewiseConstant {
+            breakpoints: self.breakpoints.clone(),
+            densities: self.densities.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<F> Distribution<F> for PiecewiseConstant<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let i = self.index.sample(rng);
+        let (low, high) = (self.breakpoints[i], self.breakpoints[i + 1]);
+        let u: F = rng.sample(StandardUniform);
+        // Rounding may yield `high`, which belongs to the next interval
+        let x = low + u * (high - low);
+        if x < high { x } else { low }
+    }
+}
+
+/// A piecewise-linear distribution over the intervals given by breakpoints.
+///
+/// Given breakpoints `b[0] < b[1] < ... < b[n]` and densities
+/// `d[0], ..., d[n]`, this is the distribution on `[b[0], b[n]]` with density
+/// proportional to `d[i]` at `b[i]` and linearly interpolated between
+/// breakpoints. This is the equivalent of C++'s
+/// `std::piecewise_linear_distribution`.
+///
+/// # Density function
+///
+/// `f(x) = ((b[i + 1] - x) d[i] + (x - b[i]) d[i + 1]) / ((b[i + 1] - b[i]) S)`
+/// for `b[i] <= x <= b[i + 1]`, where
+/// `S = Σ (d[i] + d[i + 1]) / 2 * (b[i + 1] - b[i])`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, PiecewiseLinear};
+///
+/// // A triangular distribution on [0, 2] with mode 1
+/// let d = PiecewiseLinear::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0]).unwrap();
+/// let v = d.sample(&mut rand::rng());
+/// println!("{} is from a piecewise-linear distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// The interval is selected with a [`WeightedAliasIndex`], and the sample
+/// within that interval is then generated by inversion.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "F: Serialize, F::Sampler: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: Deserialize<'de>, F::Sampler: Deserialize<'de>"))
+)]
+pub struct PiecewiseLinear<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    breakpoints: Vec<F>,
+    densities: Vec<F>,
+    index: WeightedAliasIndex<F>,
+}
+
+impl<F> PiecewiseLinear<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    /// Construct a new `PiecewiseLinear` distribution from `n + 1`
+    /// strictly increasing `breakpoints` and `n + 1` non-negative
+    /// `densities` at these breakpoints, which need not be normalised.
+    pub fn new(breakpoints: Vec<F>, densities: Vec<F>) -> Result<PiecewiseLinear<F>, Error> {
+        let num_densities = breakpoints.len();
+        let half = F::from(0.5).unwrap();
+        let index = interval_index(&breakpoints, &densities, num_densities, |i, width| {
+            (densities[i] + densities[i + 1]) * half * width
+        })?;
+        Ok(PiecewiseLinear {
+            breakpoints,
+            densities,
+            index,
+        })
+    }
+
+    /// Returns the breakpoints of the distribution.
+    pub fn breakpoints(&self) -> &[F] {
+        &self.breakpoints
+    }
+
+    /// Returns the (unnormalised) densities at the breakpoints.
+    pub fn densities(&self) -> &[F] {
+        &self.densities
+    }
+}
+
+impl<F> fmt::Debug for PiecewiseLinear<F>
+where
+    F: Float + AliasableWeight + fmt::Debug,
+    Uniform<F>: fmt::Debug,
+    StandardUniform: Distribution<F>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PiecewiseLinear")
+            .field("breakpoints", &self.breakpoints)
+            .field("densities", &self.densities)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<F> Clone for PiecewiseLinear<F>
+where
+    F: Float + AliasableWeight,
+    Uniform<F>: Clone,
+    StandardUniform: Distribution<F>,
+{
+    fn clone(&self) -> Self {
+        PiecewiseLinear {
+            breakpoints: self.breakpoints.clone(),
+            densities: self.densities.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<F> Distribution<F> for PiecewiseLinear<F>
+where
+    F: Float + AliasableWeight,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let i = self.index.sample(rng);
+        let (low, high) = (self.breakpoints[i], self.breakpoints[i + 1]);
+        let (d0, d1) = (self.densities[i], self.densities[i + 1]);
+        let u: F = rng.sample(StandardUniform);
+        // Solve d0 t + (d1 - d0) t² / 2 = u (d0 + d1) / 2 for t in [0, 1],
+        // in a form which is stable for d0 ≈ d1. The denominator is only zero
+        // for d0 = 0 and u = 0, where t = 0.
+        let denominator = d0 + (d0 * d0 + u * (d1 * d1 - d0 * d0)).sqrt();
+        let t = if denominator > F::zero() {
+            u * (d0 + d1) / denominator
+        } else {
+            F::zero()
+        };
+        (low + t * (high - low)).min(high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ConstRng;
+    use alloc::vec;
+
+    #[test]
+    fn invalid() {
+        for (b, d, err) in [
+            (vec![0.0], vec![], Error::InvalidBreakpointCount),
+            (vec![0.0, 1.0], vec![], Error::LengthMismatch),
+            (vec![0.0, 1.0], vec![1.0, 1.0], Error::LengthMismatch),
+            (vec![1.0, 0.0], vec![1.0], Error::BreakpointsNotSorted),
+            (vec![0.0, 0.0], vec![1.0], Error::BreakpointsNotSorted),
+            (vec![0.0, f64::NAN], vec![1.0], Error::BreakpointsNotSorted),
+            (vec![0.0, 1.0], vec![-1.0], Error::InvalidDensity),
+            (vec![0.0, 1.0], vec![f64::NAN], Error::InvalidDensity),
+            (vec![0.0, 1.0], vec![f64::INFINITY], Error::InvalidDensity),
+            (vec![-1e300, 1e300], vec![1e300], Error::InvalidDensity),
+            (vec![0.0, 1.0, 2.0], vec![0.0, 0.0], Error::AllDensitiesZero),
+        ] {
+            assert_eq!(PiecewiseConstant::new(b, d).unwrap_err(), err);
+        }
+
+        for (b, d, err) in [
+            (vec![], vec![], Error::InvalidBreakpointCount),
+            (vec![0.0, 1.0], vec![1.0], Error::LengthMismatch),
+            (
+                vec![0.0, 2.0, 1.0],
+                vec![1.0; 3],
+                Error::BreakpointsNotSorted,
+            ),
+            (vec![0.0, 1.0], vec![1.0, -0.5], Error::InvalidDensity),
+            (vec![0.0, 1.0], vec![0.0, 0.0], Error::AllDensitiesZero),
+        ] {
+            assert_eq!(PiecewiseLinear::new(b, d).unwrap_err(), err);
+        }
+    }
+
+    #[test]
+    fn sample() {
+        let d = PiecewiseConstant::new(vec![-1.0, 0.0, 3.0, 10.0], vec![1.0, 0.0, 0.5]).unwrap();
+        assert_eq!(d.breakpoints(), &[-1.0, 0.0, 3.0, 10.0]);
+        assert_eq!(d.densities(), &[1.0, 0.0, 0.5]);
+        let l = PiecewiseLinear::new(vec![0.0f32, 1.0, 4.0], vec![0.0, 2.0, 1.0]).unwrap();
+        let mut rng = crate::test::rng(351);
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng);
+            assert!((-1.0..0.0).contains(&x) || (3.0..10.0).contains(&x));
+            let y = l.sample(&mut rng);
+            assert!((0.0..=4.0).contains(&y));
+        }
+    }
+
+    #[test]
+    fn single_interval_median() {
+        let mut half_rng = ConstRng(0x8000_0000_0000_0000);
+        assert_eq!(half_rng.random::<f64>(), 0.5);
+
+        let d = PiecewiseConstant::new(vec![2.0, 6.0], vec![3.0]).unwrap();
+        assert_eq!(d.sample(&mut half_rng), 4.0);
+
+        // Uniform, increasing and decreasing triangular densities on [0, 1]
+        for (densities, median) in [
+            ([1.0, 1.0], 0.5),
+            ([0.0, 1.0], 0.5f64.sqrt()),
+            ([1.0, 0.0], 1.0 - 0.5f64.sqrt()),
+        ] {
+            let d = PiecewiseLinear::new(vec![0.0, 1.0], densities.to_vec()).unwrap();
+            assert_almost_eq!(d.sample(&mut half_rng), median, 1e-15);
+        }
+    }
+
+    #[test]
+    fn zero_density_at_left_edge() {
+        let mut zero_rng = ConstRng(0);
+        assert_eq!(zero_rng.random::<f64>(), 0.0);
+
+        let d = PiecewiseLinear::new(vec![2.0, 3.0], vec![0.0, 1.0]).unwrap();
+        assert_eq!(d.sample(&mut zero_rng), 2.0);
+        let d = PiecewiseLinear::new(vec![2.0f32, 3.0], vec![0.0, 1.0]).unwrap();
+        assert_eq!(d.sample(&mut zero_rng), 2.0);
+    }
+}
@@ -42,14 +42,22 @@ use rand::{Rng, RngExt};
 ///
 /// # Integer vs FP return type
 ///
-/// This implementation uses floating-point (FP) logic internally.
+/// This implementation uses floating-point (FP) logic internally, and
+/// implements [`Distribution`] for `F`, `u64` and `u32`.
 ///
-/// Due to the parameter limit <code>λ < [Self::MAX_LAMBDA]</code>, it
-/// statistically impossible to sample a value larger [`u64::MAX`]. As such, it
-/// is reasonable to cast generated samples to `u64` using `as`:
-/// `distr.sample(&mut rng) as u64` (and memory safe since Rust 1.45).
-/// Similarly, when `λ < 4.2e9` it can be safely assumed that samples are less
-/// than `u32::MAX`.
+/// Due to the parameter limit <code>λ < [Self::MAX_LAMBDA]</code>, it is
+/// statistically impossible to sample a value larger than [`u64::MAX`], so
+/// `u64` samples are exact. Similarly, when `λ < 4.2e9` it can be safely
+/// assumed that samples are less than `u32::MAX`; larger `u32` samples
+/// saturate to `u32::MAX`.
+///
+/// ```
+/// use rand_distr::{Distribution, Poisson};
+///
+/// let poi = Poisson::new(2.0).unwrap();
+/// let v: u64 = poi.sample(&mut rand::rng());
+/// println!("{} events occurred", v);
+/// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Poisson<F>(Method<F>)
@@ -86,12 +94,14 @@ impl std::error::Error for Error {}
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct KnuthMethod<F> {
+    lambda: F,
     exp_lambda: F,
 }
 
 impl<F: Float> KnuthMethod<F> {
     pub(crate) fn new(lambda: F) -> Self {
         KnuthMethod {
+            lambda,
             exp_lambda: (-lambda).exp(),
         }
     }
@@ -173,6 +183,14 @@ where
         Ok(Poisson(method))
     }
 
+    /// Returns the rate parameter `lambda` of the distribution.
+    pub fn lambda(&self) -> F {
+        match &self.0 {
+            Method::Knuth(method) => method.lambda,
+            Method::Rejection(method) => method.lambda,
+        }
+    }
+
     /// The maximum supported value of `lambda`
     ///
     /// This value was selected such that
@@ -187,7 +205,7 @@ where
 
 impl<F> Distribution<F> for KnuthMethod<F>
 where
-    F: Float + FloatConst,
+    F: Float,
     StandardUniform: Distribution<F>,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
@@ -323,6 +341,34 @@ where
     }
 }
 
+impl<F> Distribution<u64> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let x: F = self.sample(rng);
+        x.to_u64().unwrap_or(u64::MAX)
+    }
+}
+
+impl<F> Distribution<u32> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+        let x: F = self.sample(rng);
+        x.to_u32().unwrap_or(u32::MAX)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -345,6 +391,30 @@ mod test {
         Poisson::new(-10.0).unwrap();
     }
 
+    #[test]
+    fn test_poisson_integer_samples() {
+        for lambda in [2.0, 1000.0] {
+            let d = Poisson::new(lambda).unwrap();
+            let (mut rng_f, mut rng_u64, mut rng_u32) = (
+                crate::test::rng(491),
+                crate::test::rng(491),
+                crate::test::rng(491),
+            );
+            for _ in 0..100 {
+                let x: f64 = d.sample(&mut rng_f);
+                let k: u64 = d.sample(&mut rng_u64);
+                let j: u32 = d.sample(&mut rng_u32);
+                assert_eq!((x as u64, x as u32), (k, j));
+            }
+        }
+
+        // saturating u32 samples
+        let d = Poisson::new(1e12).unwrap();
+        let mut rng = crate::test::rng(492);
+        let j: u32 = d.sample(&mut rng);
+        assert_eq!(j, u32::MAX);
+    }
+
     #[test]
     fn poisson_distributions_can_be_compared() {
         assert_eq!(Poisson::new(1.0), Poisson::new(1.0));
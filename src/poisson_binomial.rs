@@ -0,0 +1,336 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Poisson binomial distribution.
+
+use crate::weighted::WeightedAliasIndex;
+use crate::{Distribution, StandardNormal};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Poisson binomial distribution](https://en.wikipedia.org/wiki/Poisson_binomial_distribution)
+/// `PoissonBinomial(p₁, p₂, ..., pₙ)`.
+///
+/// This is the distribution of the number of successes in `n` independent
+/// [`Bernoulli`](crate::Bernoulli) trials, where trial `i` succeeds with
+/// probability `pᵢ`. When all `pᵢ` are equal, it is the
+/// [`Binomial`](crate::Binomial) distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, PoissonBinomial};
+///
+/// // The number of working components of a system
+/// let working = PoissonBinomial::new(&[0.99, 0.95, 0.9, 0.8]).unwrap();
+/// let v = working.sample(&mut rand::rng());
+/// println!("{} components are working", v);
+/// ```
+///
+/// # Notes
+///
+/// The sampling method depends on the number of trials `n`:
+///
+/// - For few trials, samples are generated by summing the outcomes of the
+///   trials, at a cost of `n` uniform samples.
+/// - Otherwise, up to a moderate number of trials, the exact density is
+///   computed at construction, in `O(n²)` time, and sampled with a
+///   [`WeightedAliasIndex`].
+/// - For many trials with a variance `Σ pᵢ (1 - pᵢ)` of at least 25, samples
+///   are generated from the refined normal approximation of Volkova (1996),
+///   by rounding a Cornish–Fisher transform of a normal sample that
+///   corrects for the skewness.
+/// - For many trials with a smaller variance, the normal approximation is
+///   not accurate. If at most a moderate number of the `pᵢ` are distinct
+///   from 0 and 1, the exact density of these trials is sampled with a
+///   [`WeightedAliasIndex`]. Otherwise the outcomes of all trials are
+///   summed, at a cost of `n` uniform samples.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoissonBinomial {
+    probabilities: Box<[f64]>,
+    method: Method,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Direct,
+    // The density of the trials with probabilities strictly between 0 and 1,
+    // and the number of trials with probability 1
+    Alias(WeightedAliasIndex<f64>, u64),
+    RefinedNormal {
+        mean: f64,
+        std_dev: f64,
+        skewness: f64,
+    },
+}
+
+// The sampling method is determined by the probabilities.
+impl PartialEq for PoissonBinomial {
+    fn eq(&self, other: &Self) -> bool {
+        self.probabilities == other.probabilities
+    }
+}
+
+/// Error type returned from [`PoissonBinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A probability is outside the interval `[0, 1]` or `nan`.
+    InvalidProbability,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidProbability => {
+                "a probability is NaN or outside the interval [0, 1] in Poisson binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// The density of the number of successes, by dynamic programming over the
+/// trials.
+fn exact_pmf(probabilities: &[f64]) -> Vec<f64> {
+    let mut pmf = vec![0.0; probabilities.len() + 1];
+    pmf[0] = 1.0;
+    for (i, &p) in probabilities.iter().enumerate() {
+        let q = 1.0 - p;
+        for k in (1..=i + 1).rev() {
+            pmf[k] = pmf[k] * q + pmf[k - 1] * p;
+        }
+        pmf[0] *= q;
+    }
+    pmf
+}
+
+impl PoissonBinomial {
+    /// Construct a new `PoissonBinomial` distribution with the success
+    /// `probabilities` of the trials.
+    pub fn new(probabilities: &[f64]) -> Result<PoissonBinomial, Error> {
+        if !probabilities.iter().all(|p| (0.0..=1.0).contains(p)) {
+            return Err(Error::InvalidProbability);
+        }
+
+        // Summing is cheaper than an alias table lookup for few trials. The
+        // alias table needs O(n²) time to set up and O(n) memory.
+        const DIRECT_THRESHOLD: usize = 16;
+        const ALIAS_THRESHOLD: usize = 1000;
+        // Below this variance, the normal approximation is not accurate.
+        const MIN_NORMAL_VARIANCE: f64 = 25.0;
+
+        let n = probabilities.len();
+        let method = if n <= DIRECT_THRESHOLD {
+            Method::Direct
+        } else if n <= ALIAS_THRESHOLD {
+            let pmf = exact_pmf(probabilities);
+            Method::Alias(WeightedAliasIndex::new(pmf).unwrap(), 0)
+        } else {
+            let mut mean = 0.0;
+            let mut variance = 0.0;
+            let mut third_moment = 0.0;
+            for &p in probabilities {
+                let v = p * (1.0 - p);
+                mean += p;
+                variance += v;
+                third_moment += v * (1.0 - 2.0 * p);
+            }
+            if variance >= MIN_NORMAL_VARIANCE {
+                let std_dev = variance.sqrt();
+                Method::RefinedNormal {
+                    mean,
+                    std_dev,
+                    skewness: third_moment / (variance * std_dev),
+                }
+            } else {
+                // The trials with probability 0 or 1 do not contribute to
+                // the variance.
+                let certain = probabilities.iter().filter(|&&p| p == 1.0).count();
+                let uncertain: Vec<f64> = probabilities
+                    .iter()
+                    .copied()
+                    .filter(|&p| p > 0.0 && p < 1.0)
+                    .collect();
+                if uncertain.len() <= ALIAS_THRESHOLD {
+                    let pmf = exact_pmf(&uncertain);
+                    Method::Alias(WeightedAliasIndex::new(pmf).unwrap(), certain as u64)
+                } else {
+                    Method::Direct
+                }
+            }
+        };
+
+        Ok(PoissonBinomial {
+            probabilities: probabilities.into(),
+            method,
+        })
+    }
+
+    /// Returns the success probabilities of the trials.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.probabilities.len() as u64
+    }
+
+    /// Returns the exact probability density function, i.e. the
+    /// probabilities of `0, 1, ..., n` successes.
+    ///
+    /// This takes `O(n²)` time.
+    pub fn pmf(&self) -> Vec<f64> {
+        exact_pmf(&self.probabilities)
+    }
+}
+
+impl Distribution<u64> for PoissonBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match &self.method {
+            Method::Direct => self
+                .probabilities
+                .iter()
+                .filter(|&&p| rng.random::<f64>() < p)
+                .count() as u64,
+            Method::Alias(alias, certain) => certain + alias.sample(rng) as u64,
+            Method::RefinedNormal {
+                mean,
+                std_dev,
+                skewness,
+            } => {
+                let z: f64 = StandardNormal.sample(rng);
+                let x = z + skewness * (z * z - 1.0) / 6.0;
+                let k = (mean + std_dev * x).round();
+                k.max(0.0).min(self.probabilities.len() as f64) as u64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_probabilities() {
+        for p in [-0.1, 1.1, f64::NAN] {
+            assert_eq!(
+                PoissonBinomial::new(&[0.5, p]),
+                Err(Error::InvalidProbability)
+            );
+        }
+        assert!(PoissonBinomial::new(&[0.0, 1.0]).is_ok());
+    }
+
+    #[test]
+    fn test_no_trials() {
+        let d = PoissonBinomial::new(&[]).unwrap();
+        let mut rng = crate::test::VoidRng;
+        assert_eq!(d.sample(&mut rng), 0);
+        assert_eq!(d.pmf(), [1.0]);
+    }
+
+    #[test]
+    fn test_pmf() {
+        let d = PoissonBinomial::new(&[0.5, 0.2, 1.0]).unwrap();
+        let pmf = d.pmf();
+        let expected = [0.0, 0.4, 0.5, 0.1];
+        for (p, e) in pmf.iter().zip(expected) {
+            assert_almost_eq!(p, e, 1e-15);
+        }
+
+        // The binomial case
+        let d = PoissonBinomial::new(&[0.3; 10]).unwrap();
+        let pmf = d.pmf();
+        assert_almost_eq!(pmf[0], 0.7f64.powi(10), 1e-15);
+        assert_almost_eq!(pmf[3], 120.0 * 0.3f64.powi(3) * 0.7f64.powi(7), 1e-15);
+        assert_almost_eq!(pmf.iter().sum::<f64>(), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        // covers all three methods
+        let mut rng = crate::test::rng(461);
+        for n in [10, 200, 5000] {
+            let probabilities: Vec<f64> = (0..n).map(|i| (i % 10) as f64 / 10.0).collect();
+            let d = PoissonBinomial::new(&probabilities).unwrap();
+            let expected_mean = probabilities.iter().sum::<f64>();
+            let expected_variance = probabilities.iter().map(|p| p * (1.0 - p)).sum::<f64>();
+
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < expected_mean / 100.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+        }
+    }
+
+    #[test]
+    fn test_small_variance() {
+        // Many trials, but a variance for which the normal approximation is
+        // not accurate
+        let mut with_certain = vec![0.99; 2000];
+        with_certain[..100].fill(0.0);
+        with_certain[100..1300].fill(1.0);
+        let cases = [vec![0.999; 2000], with_certain, vec![1e-4; 2000]];
+
+        let mut rng = crate::test::rng(462);
+        for probabilities in cases {
+            let d = PoissonBinomial::new(&probabilities).unwrap();
+            let pmf = d.pmf();
+            let expected_mean = probabilities.iter().sum::<f64>();
+            let expected_variance = probabilities.iter().map(|p| p * (1.0 - p)).sum::<f64>();
+            let min = probabilities.iter().filter(|&&p| p == 1.0).count() as u64;
+            let max = probabilities.iter().filter(|&&p| p > 0.0).count() as u64;
+            let mode = (0..pmf.len())
+                .max_by(|&i, &j| pmf[i].total_cmp(&pmf[j]))
+                .unwrap() as u64;
+
+            let mut results = [0.0; 10000];
+            let mut mode_count = 0;
+            for i in results.iter_mut() {
+                let k = d.sample(&mut rng);
+                assert!((min..=max).contains(&k));
+                mode_count += (k == mode) as u32;
+                *i = k as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < 0.05);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+
+            let expected_count = pmf[mode as usize] * results.len() as f64;
+            assert!((mode_count as f64 - expected_count).abs() < 4.0 * expected_count.sqrt());
+        }
+    }
+
+    #[test]
+    fn poisson_binomial_distributions_can_be_compared() {
+        assert_eq!(
+            PoissonBinomial::new(&[0.1, 0.2]),
+            PoissonBinomial::new(&[0.1, 0.2])
+        );
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Skellam distribution `Skellam(μ₁, μ₂)`.
+
+use crate::{Distribution, Poisson};
+use core::fmt;
+use rand::Rng;
+
+/// The [Skellam distribution](https://en.wikipedia.org/wiki/Skellam_distribution)
+/// `Skellam(μ₁, μ₂)`.
+///
+/// This is the distribution of the difference `N₁ - N₂` of independent
+/// Poisson-distributed random variables `N₁ ~ Poisson(μ₁)` and
+/// `N₂ ~ Poisson(μ₂)`, e.g. of the goal difference of a football match. It
+/// has mean `μ₁ - μ₂` and variance `μ₁ + μ₂`.
+///
+/// # Density function
+///
+/// `f(k) = exp(-(μ₁ + μ₂)) (μ₁ / μ₂)^(k / 2) I_k(2 sqrt(μ₁ μ₂))`, where `I_k`
+/// is the modified Bessel function of the first kind.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Skellam};
+///
+/// let skellam = Skellam::new(1.6, 1.1).unwrap();
+/// let goal_difference: i64 = skellam.sample(&mut rand::rng());
+/// println!("{} is from a Skellam distribution", goal_difference);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated as the difference of two [`Poisson`] samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skellam {
+    poisson_1: Poisson<f64>,
+    poisson_2: Poisson<f64>,
+}
+
+/// Error type returned from [`Skellam::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `mu1` is not a valid [`Poisson`] rate.
+    Mu1Invalid,
+    /// `mu2` is not a valid [`Poisson`] rate.
+    Mu2Invalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::Mu1Invalid => "mu1 is not a valid Poisson rate in Skellam distribution",
+            Error::Mu2Invalid => "mu2 is not a valid Poisson rate in Skellam distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl Skellam {
+    /// Construct a new `Skellam` distribution with the rates `mu1` and `mu2`
+    /// of the minuend and subtrahend.
+    ///
+    /// The constraints on the rates are those of [`Poisson::new`].
+    pub fn new(mu1: f64, mu2: f64) -> Result<Skellam, Error> {
+        Ok(Skellam {
+            poisson_1: Poisson::new(mu1).map_err(|_| Error::Mu1Invalid)?,
+            poisson_2: Poisson::new(mu2).map_err(|_| Error::Mu2Invalid)?,
+        })
+    }
+
+    /// Returns the rate `mu1` of the minuend.
+    pub fn mu1(&self) -> f64 {
+        self.poisson_1.lambda()
+    }
+
+    /// Returns the rate `mu2` of the subtrahend.
+    pub fn mu2(&self) -> f64 {
+        self.poisson_2.lambda()
+    }
+}
+
+impl Distribution<i64> for Skellam {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
+        let n1: f64 = self.poisson_1.sample(rng);
+        let n2: f64 = self.poisson_2.sample(rng);
+        // saturating cast
+        (n1 - n2) as i64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(Skellam::new(0.0, 1.0), Err(Error::Mu1Invalid));
+        assert_eq!(Skellam::new(f64::NAN, 1.0), Err(Error::Mu1Invalid));
+        assert_eq!(Skellam::new(1.0, -1.0), Err(Error::Mu2Invalid));
+        assert_eq!(Skellam::new(1.0, f64::INFINITY), Err(Error::Mu2Invalid));
+        let skellam = Skellam::new(2.0, 3.0).unwrap();
+        assert_eq!((skellam.mu1(), skellam.mu2()), (2.0, 3.0));
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(451);
+        for (mu1, mu2) in [(1.0, 3.0), (20.0, 5.0)] {
+            let d = Skellam::new(mu1, mu2).unwrap();
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - (mu1 - mu2)).abs() < 0.1);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - (mu1 + mu2)).abs() < (mu1 + mu2) / 10.0);
+        }
+    }
+
+    #[test]
+    fn skellam_distributions_can_be_compared() {
+        assert_eq!(Skellam::new(1.0, 2.0), Skellam::new(1.0, 2.0));
+    }
+}
@@ -9,14 +9,15 @@
 
 //! The Student's t-distribution.
 
-use crate::{ChiSquared, ChiSquaredError};
+use crate::ChiSquared;
 use crate::{Distribution, Exp1, Open01, StandardNormal};
+use core::fmt;
 use num_traits::Float;
 use rand::{Rng, RngExt};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// The [Student t-distribution](https://en.wikipedia.org/wiki/Student%27s_t-distribution) `t(ν)`.
+/// The [Student t-distribution](https://en.wikipedia.org/wiki/Student%27s_t-distribution) `t(ν, μ, σ)`.
 ///
 /// The t-distribution is a continuous probability distribution
 /// parameterized by degrees of freedom `ν` (`nu`), which
@@ -25,10 +26,16 @@ use serde::{Deserialize, Serialize};
 /// the population's standard deviation is unknown.
 /// It is widely used in hypothesis testing.
 ///
+/// The standard t-distribution `t(ν)` is constructed with [`StudentT::new`].
+/// The location-scale generalisation `μ + σ T`, where `T ~ t(ν)`, is
+/// constructed with [`StudentT::with_location_scale`].
+///
 /// For `ν = 1`, this is equivalent to the standard
 /// [`Cauchy`](crate::Cauchy) distribution,
 /// and as `ν` diverges to infinity, `t(ν)` converges to
-/// [`StandardNormal`](crate::StandardNormal).
+/// [`StandardNormal`](crate::StandardNormal). The limit `ν = ∞` is
+/// accepted and samples from the [`Normal`](crate::Normal) distribution
+/// `N(μ, σ²)`.
 ///
 /// # Plot
 ///
@@ -43,7 +50,11 @@ use serde::{Deserialize, Serialize};
 ///
 /// let t = StudentT::new(11.0).unwrap();
 /// let v = t.sample(&mut rand::rng());
-/// println!("{} is from a t(11) distribution", v)
+/// println!("{} is from a t(11) distribution", v);
+///
+/// let t = StudentT::with_location_scale(4.0, 10.0, 2.0).unwrap();
+/// let v = t.sample(&mut rand::rng());
+/// println!("{} is from a t(4, 10, 2) distribution", v);
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -54,10 +65,38 @@ where
     Exp1: Distribution<F>,
     Open01: Distribution<F>,
 {
-    chi: ChiSquared<F>,
+    // `None` in the limit `ν = ∞`.
+    chi: Option<ChiSquared<F>>,
     dof: F,
+    location: F,
+    scale: F,
+}
+
+/// Error type returned from [`StudentT::new`] and [`StudentT::with_location_scale`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Error {
+    /// `nu <= 0` or `nan`.
+    DoFTooSmall,
+    /// `location` is infinite or `nan`.
+    LocationNotFinite,
+    /// `scale <= 0`, infinite or `nan`.
+    ScaleNotPositive,
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::DoFTooSmall => "degrees-of-freedom nu is not positive in t-distribution",
+            Error::LocationNotFinite => "location is not finite in t-distribution",
+            Error::ScaleNotPositive => "scale is not positive and finite in t-distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 impl<F> StudentT<F>
 where
     F: Float,
@@ -67,12 +106,57 @@ where
 {
     /// Create a new Student t-distribution with `ν` (nu)
     /// degrees of freedom.
-    pub fn new(nu: F) -> Result<StudentT<F>, ChiSquaredError> {
+    ///
+    /// This is equivalent to `StudentT::with_location_scale(nu, 0.0, 1.0)`.
+    pub fn new(nu: F) -> Result<StudentT<F>, Error> {
+        Self::with_location_scale(nu, F::zero(), F::one())
+    }
+
+    /// Create a new location-scale Student t-distribution with `ν` (nu)
+    /// degrees of freedom, location `μ` (mu) and scale `σ` (sigma).
+    ///
+    /// Parameters:
+    ///
+    /// -   degrees of freedom (`ν > 0`, may be infinite)
+    /// -   location (`μ`, must be finite)
+    /// -   scale (`σ > 0`, must be finite)
+    pub fn with_location_scale(nu: F, mu: F, sigma: F) -> Result<StudentT<F>, Error> {
+        if !(nu > F::zero()) {
+            return Err(Error::DoFTooSmall);
+        }
+        if !mu.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !(sigma > F::zero()) || sigma.is_infinite() {
+            return Err(Error::ScaleNotPositive);
+        }
+        let chi = if nu.is_infinite() {
+            None
+        } else {
+            Some(ChiSquared::new(nu).map_err(|_| Error::DoFTooSmall)?)
+        };
         Ok(StudentT {
-            chi: ChiSquared::new(nu)?,
+            chi,
             dof: nu,
+            location: mu,
+            scale: sigma,
         })
     }
+
+    /// Returns the degrees of freedom (`ν`) of the distribution.
+    pub fn nu(&self) -> F {
+        self.dof
+    }
+
+    /// Returns the location (`μ`) of the distribution.
+    pub fn location(&self) -> F {
+        self.location
+    }
+
+    /// Returns the scale (`σ`) of the distribution.
+    pub fn scale(&self) -> F {
+        self.scale
+    }
 }
 impl<F> Distribution<F> for StudentT<F>
 where
@@ -83,7 +167,84 @@ where
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
         let norm: F = rng.sample(StandardNormal);
-        norm * (self.dof / self.chi.sample(rng)).sqrt()
+        let t = match self.chi {
+            Some(ref chi) => norm * (self.dof / chi.sample(rng)).sqrt(),
+            None => norm,
+        };
+        self.location + self.scale * t
+    }
+}
+
+/// The half-t distribution `t⁺(ν, σ)`.
+///
+/// This is the distribution of `|X|` where `X ~ t(ν, 0, σ)`, i.e. the
+/// location-scale [`StudentT`] distribution with location zero folded onto
+/// the non-negative reals. It is a common weakly informative prior for scale
+/// parameters, interpolating between the
+/// [`HalfCauchy`](crate::HalfCauchy) (`ν = 1`) and the
+/// [`HalfNormal`](crate::HalfNormal) (`ν = ∞`) distributions.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{HalfStudentT, Distribution};
+///
+/// let t = HalfStudentT::new(3.0, 2.5).unwrap();
+/// let v = t.sample(&mut rand::rng());
+/// println!("{} is from a half-t(3, 2.5) distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HalfStudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    t: StudentT<F>,
+}
+
+impl<F> HalfStudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Create a new half-t distribution with `ν` (nu) degrees of freedom and
+    /// scale `σ` (sigma).
+    ///
+    /// Parameters:
+    ///
+    /// -   degrees of freedom (`ν > 0`, may be infinite)
+    /// -   scale (`σ > 0`, must be finite)
+    pub fn new(nu: F, sigma: F) -> Result<HalfStudentT<F>, Error> {
+        Ok(HalfStudentT {
+            t: StudentT::with_location_scale(nu, F::zero(), sigma)?,
+        })
+    }
+
+    /// Returns the degrees of freedom (`ν`) of the distribution.
+    pub fn nu(&self) -> F {
+        self.t.nu()
+    }
+
+    /// Returns the scale (`σ`) of the distribution.
+    pub fn scale(&self) -> F {
+        self.t.scale()
+    }
+}
+
+impl<F> Distribution<F> for HalfStudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        self.t.sample(rng).abs()
     }
 }
 
@@ -100,8 +261,68 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_t_f32() {
+        let t = StudentT::with_location_scale(3.0f32, -2.0, 0.5).unwrap();
+        let mut rng = crate::test::rng(206);
+        for _ in 0..1000 {
+            assert!(t.sample(&mut rng).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_t_infinite_nu() {
+        // ν = ∞ is the Normal distribution N(μ, σ²)
+        let t = StudentT::with_location_scale(f64::INFINITY, 5.0, 2.0).unwrap();
+        let normal = crate::Normal::new(5.0, 2.0).unwrap();
+        let mut rng1 = crate::test::rng(207);
+        let mut rng2 = crate::test::rng(207);
+        for _ in 0..100 {
+            assert_eq!(t.sample(&mut rng1), normal.sample(&mut rng2));
+        }
+    }
+
+    #[test]
+    fn test_t_invalid() {
+        assert_eq!(StudentT::new(0.0), Err(Error::DoFTooSmall));
+        assert_eq!(StudentT::new(f64::NAN), Err(Error::DoFTooSmall));
+        assert_eq!(
+            StudentT::with_location_scale(1.0, f64::NAN, 1.0),
+            Err(Error::LocationNotFinite)
+        );
+        assert_eq!(
+            StudentT::with_location_scale(1.0, 0.0, 0.0),
+            Err(Error::ScaleNotPositive)
+        );
+        assert_eq!(
+            StudentT::with_location_scale(1.0, 0.0, f64::INFINITY),
+            Err(Error::ScaleNotPositive)
+        );
+    }
+
+    #[test]
+    fn test_t_accessors() {
+        let t = StudentT::with_location_scale(4.0, 1.0, 3.0).unwrap();
+        assert_eq!((t.nu(), t.location(), t.scale()), (4.0, 1.0, 3.0));
+        let t = StudentT::new(2.5).unwrap();
+        assert_eq!((t.nu(), t.location(), t.scale()), (2.5, 0.0, 1.0));
+    }
+
     #[test]
     fn student_t_distributions_can_be_compared() {
         assert_eq!(StudentT::new(1.0), StudentT::new(1.0));
     }
+
+    #[test]
+    fn test_half_t() {
+        assert_eq!(HalfStudentT::new(0.0, 1.0), Err(Error::DoFTooSmall));
+        assert_eq!(HalfStudentT::new(1.0, -1.0), Err(Error::ScaleNotPositive));
+        let d = HalfStudentT::new(3.0, 2.5).unwrap();
+        assert_eq!((d.nu(), d.scale()), (3.0, 2.5));
+        let mut rng = crate::test::rng(208);
+        for _ in 0..1000 {
+            assert!(d.sample(&mut rng) >= 0.0);
+        }
+        assert_eq!(HalfStudentT::new(1.0, 2.0), HalfStudentT::new(1.0, 2.0));
+    }
 }
@@ -0,0 +1,249 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//! The trapezoidal distribution.
+
+use crate::{Distribution, StandardUniform};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [trapezoidal distribution](https://en.wikipedia.org/wiki/Trapezoidal_distribution) `Trapezoidal(min, max, lower, upper)`.
+///
+/// A continuous probability distribution parameterised by a range and an
+/// interval `[lower, upper]` of most likely values within that range. The
+/// density rises linearly from `min` to `lower`, is constant between `lower`
+/// and `upper`, and falls linearly from `upper` to `max`.
+///
+/// For `lower == upper` this is the [`Triangular`] distribution, and for
+/// `lower == min` and `upper == max` it is the uniform distribution on
+/// `[min, max]`.
+///
+/// The [generalized trapezoidal distribution][Trapezoidal::generalized] of
+/// van Dorp & Kotz[^1] replaces the linear growth and decay stages by
+/// power-law stages with exponents `m` and `n`, with density proportional to
+///
+/// - `((x - min) / (lower - min))^(m - 1)` for `min <= x < lower`,
+/// - `1` for `lower <= x < upper`,
+/// - `((max - x) / (max - upper))^(n - 1)` for `upper <= x <= max`.
+///
+/// The (linear) trapezoidal distribution is the case `m = n = 2`.
+///
+/// # Example
+///
+/// ```rust
+/// use rand_distr::{Trapezoidal, Distribution};
+///
+/// let d = Trapezoidal::new(0., 10., 2., 5.).unwrap();
+/// let v = d.sample(&mut rand::rng());
+/// println!("{} is from a trapezoidal distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Sampling uses inversion of the distribution function.
+///
+/// [`Triangular`]: crate::Triangular
+/// [^1]: J. René van Dorp and Samuel Kotz (2003).
+///       *Generalized trapezoidal distributions*. Metrika 58, 85–97.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trapezoidal<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    min: F,
+    max: F,
+    lower: F,
+    upper: F,
+    inv_growth: F,
+    inv_decay: F,
+    // Sum of the (unnormalised) masses of the three stages
+    total: F,
+    // Probability of x < lower
+    p_lower: F,
+    // Probability of x < upper
+    p_upper: F,
+}
+
+/// Error type returned from [`Trapezoidal::new`] and [`Trapezoidal::generalized`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrapezoidalError {
+    /// `max < min` or `min` or `max` is NaN.
+    RangeTooSmall,
+    /// `min <= lower <= upper <= max` is not met or `lower` or `upper` is NaN.
+    ModeRange,
+    /// `growth` or `decay` is not positive and finite.
+    ExponentNotPositive,
+}
+
+impl fmt::Display for TrapezoidalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TrapezoidalError::RangeTooSmall => {
+                "requirement min <= max is not met in trapezoidal distribution"
+            }
+            TrapezoidalError::ModeRange => {
+                "requirement min <= lower <= upper <= max is not met in trapezoidal distribution"
+            }
+            TrapezoidalError::ExponentNotPositive => {
+                "growth or decay is not positive and finite in trapezoidal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TrapezoidalError {}
+
+impl<F> Trapezoidal<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    /// Set up the Trapezoidal distribution with defined `min`, `max`, and
+    /// modal interval `[lower, upper]`.
+    #[inline]
+    pub fn new(min: F, max: F, lower: F, upper: F) -> Result<Trapezoidal<F>, TrapezoidalError> {
+        let two = F::one() + F::one();
+        Self::generalized(min, max, lower, upper, two, two)
+    }
+
+    /// Set up the generalized Trapezoidal distribution with defined `min`,
+    /// `max`, modal interval `[lower, upper]`, and exponents `growth` (`m`)
+    /// and `decay` (`n`) of the first and last stage.
+    ///
+    /// With `growth == decay == 2` this is the same as [`Trapezoidal::new`].
+    pub fn generalized(
+        min: F,
+        max: F,
+        lower: F,
+        upper: F,
+        growth: F,
+        decay: F,
+    ) -> Result<Trapezoidal<F>, TrapezoidalError> {
+        if !(max >= min) {
+            return Err(TrapezoidalError::RangeTooSmall);
+        }
+        if !(lower >= min && upper >= lower && max >= upper) {
+            return Err(TrapezoidalError::ModeRange);
+        }
+        if !(growth > F::zero() && decay > F::zero()) || growth.is_infinite() || decay.is_infinite()
+        {
+            return Err(TrapezoidalError::ExponentNotPositive);
+        }
+
+        let left = (lower - min) / growth;
+        let middle = upper - lower;
+        let right = (max - upper) / decay;
+        let total = left + middle + right;
+        let (p_lower, p_upper) = if total > F::zero() {
+            (left / total, (left + middle) / total)
+        } else {
+            // min == max: always sample from the (empty) middle stage
+            (F::zero(), F::one())
+        };
+
+        Ok(Trapezoidal {
+            min,
+            max,
+            lower,
+            upper,
+            inv_growth: F::one() / growth,
+            inv_decay: F::one() / decay,
+            total,
+            p_lower,
+            p_upper,
+        })
+    }
+}
+
+impl<F> Distribution<F> for Trapezoidal<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let u: F = rng.sample(StandardUniform);
+        if u < self.p_lower {
+            self.min + (self.lower - self.min) * (u / self.p_lower).powf(self.inv_growth)
+        } else if u < self.p_upper {
+            self.lower + (u - self.p_lower) * self.total
+        } else {
+            let v = (F::one() - u) / (F::one() - self.p_upper);
+            self.max - (self.max - self.upper) * v.powf(self.inv_decay)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::ConstRng;
+
+    #[test]
+    fn test_trapezoidal() {
+        let mut half_rng = ConstRng(0x8000_0000_0000_0000);
+        assert_eq!(half_rng.random::<f64>(), 0.5);
+        for &(min, max, lower, upper, median) in &[
+            (-1., 1., -0.5, 0.5, 0.),
+            (0., 4., 1., 2., 1.75),
+            (0., 10., 0., 10., 5.),
+            (1., 1., 1., 1., 1.),
+            (0., 2., 2., 2., 2f64.sqrt()),
+            (0., 1., 0., 0., 1. - 0.5f64.sqrt()),
+        ] {
+            let distr = Trapezoidal::new(min, max, lower, upper).unwrap();
+            // Test correct value at median:
+            assert_almost_eq!(distr.sample(&mut half_rng), median, 1e-15);
+        }
+
+        for &(min, max, lower, upper) in &[
+            (2., 1., 1., 1.),
+            (f64::NAN, 1., 0., 0.),
+            (-1., 1., 0.5, -0.5),
+            (-1., 1., -2., 0.),
+            (-1., 1., 0., 2.),
+            (-1., 1., f64::NAN, 0.),
+        ] {
+            assert!(Trapezoidal::new(min, max, lower, upper).is_err());
+        }
+        assert_eq!(
+            Trapezoidal::generalized(0., 1., 0.2, 0.8, 0., 1.),
+            Err(TrapezoidalError::ExponentNotPositive)
+        );
+        assert_eq!(
+            Trapezoidal::generalized(0., 1., 0.2, 0.8, 1., f64::INFINITY),
+            Err(TrapezoidalError::ExponentNotPositive)
+        );
+    }
+
+    #[test]
+    fn test_generalized_trapezoidal() {
+        // Symmetric, so the median is still at the centre
+        let d = Trapezoidal::generalized(0., 10., 3., 7., 3., 3.).unwrap();
+        let mut half_rng = ConstRng(0x8000_0000_0000_0000);
+        assert_almost_eq!(d.sample(&mut half_rng), 5., 1e-15);
+
+        let d = Trapezoidal::generalized(-2., 5., 1., 1.5, 0.5, 4.).unwrap();
+        let mut rng = crate::test::rng(311);
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng);
+            assert!((-2. ..=5.).contains(&x));
+        }
+    }
+
+    #[test]
+    fn trapezoidal_distributions_can_be_compared() {
+        assert_eq!(
+            Trapezoidal::new(1.0, 4.0, 2.0, 3.0),
+            Trapezoidal::new(1.0, 4.0, 2.0, 3.0)
+        );
+    }
+}
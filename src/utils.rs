@@ -95,3 +95,51 @@ where
         }
     }
 }
+
+/// The natural logarithm of the gamma function, for `x > 0`.
+///
+/// Uses the Lanczos approximation with `g = 7` and `n = 9`, which is
+/// accurate to about 15 significant digits.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.99999999999980993,
+        676.5203681218851,
+        -1259.1392167224028,
+        771.32342877765313,
+        -176.61502916214059,
+        12.507343278686905,
+        -0.13857109526572012,
+        9.9843695780195716e-6,
+        1.5056327351493116e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula
+        let pi = core::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let mut a = COEFFICIENTS[0];
+    let t = x + G + 0.5;
+    for (i, &c) in COEFFICIENTS.iter().enumerate().skip(1) {
+        a += c / (x + i as f64);
+    }
+    0.5 * (2.0 * core::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ln_gamma() {
+        for x in [1e-8, 0.1, 0.5, 1.0, 1.5, 2.0, 3.7, 10.0, 171.3, 1e6] {
+            let expected = special::Gamma::ln_gamma(x).0;
+            assert_almost_eq!(ln_gamma(x), expected, 1e-13 * expected.abs().max(1.0));
+        }
+        assert_almost_eq!(ln_gamma(1.0), 0.0, 1e-15);
+        assert_almost_eq!(ln_gamma(5.0), 24f64.ln(), 1e-14);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The variance-gamma distribution `VG(μ, θ, σ, ν)`.
+
+use crate::{Distribution, Exp1, Gamma, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [variance-gamma distribution](https://en.wikipedia.org/wiki/Variance-gamma_distribution) `VG(μ, θ, σ, ν)`.
+///
+/// This is the normal variance-mean mixture
+/// `X = μ + θ G + σ sqrt(G) Z`, where `Z` is [`StandardNormal`] and the
+/// mixing variable `G ~ Gamma(1/ν, ν)` has mean 1 and variance `ν`.
+/// It is the distribution of the increments of the variance-gamma process
+/// of Madan, Carr & Chang[^1] over unit time, with location `μ`, drift `θ`,
+/// volatility `σ` and variance rate `ν`.
+///
+/// For `θ = 0` and `ν = 1` this is a Laplace distribution with location `μ`
+/// and scale `σ / sqrt(2)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{VarianceGamma, Distribution};
+///
+/// let vg = VarianceGamma::new(0.0, -0.14, 0.12, 0.17).unwrap();
+/// let v = vg.sample(&mut rand::rng());
+/// println!("{} is from a variance-gamma distribution", v);
+/// ```
+///
+/// [^1]: Dilip B. Madan, Peter P. Carr and Eric C. Chang (1998).
+///       *The Variance Gamma Process and Option Pricing*.
+///       European Finance Review 2, 79–105.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VarianceGamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    mu: F,
+    theta: F,
+    sigma: F,
+    gamma: Gamma<F>,
+}
+
+/// Error type returned from [`VarianceGamma::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `mu` is infinite or `nan`.
+    LocationNotFinite,
+    /// `theta` is infinite or `nan`.
+    DriftNotFinite,
+    /// `sigma <= 0`, infinite or `nan`.
+    SigmaNotPositive,
+    /// `nu <= 0`, infinite or `nan`.
+    NuNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::LocationNotFinite => "mu is not finite in variance-gamma distribution",
+            Error::DriftNotFinite => "theta is not finite in variance-gamma distribution",
+            Error::SigmaNotPositive => {
+                "sigma is not positive and finite in variance-gamma distribution"
+            }
+            Error::NuNotPositive => "nu is not positive and finite in variance-gamma distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F> VarianceGamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `VarianceGamma` distribution with location `mu`
+    /// (`μ`), drift `theta` (`θ`), volatility `sigma` (`σ`) and variance
+    /// rate `nu` (`ν`).
+    ///
+    /// Parameters:
+    ///
+    /// -   `mu` (must be finite)
+    /// -   `theta` (must be finite)
+    /// -   `sigma` (must be positive and finite)
+    /// -   `nu` (must be positive and finite)
+    pub fn new(mu: F, theta: F, sigma: F, nu: F) -> Result<VarianceGamma<F>, Error> {
+        if !mu.is_finite() {
+            return Err(Error::LocationNotFinite);
+        }
+        if !theta.is_finite() {
+            return Err(Error::DriftNotFinite);
+        }
+        if !(sigma > F::zero()) || sigma.is_infinite() {
+            return Err(Error::SigmaNotPositive);
+        }
+        if !(nu > F::zero()) || nu.is_infinite() {
+            return Err(Error::NuNotPositive);
+        }
+        let gamma = Gamma::new(F::one() / nu, nu).map_err(|_| Error::NuNotPositive)?;
+        Ok(VarianceGamma {
+            mu,
+            theta,
+            sigma,
+            gamma,
+        })
+    }
+}
+
+impl<F> Distribution<F> for VarianceGamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let g = self.gamma.sample(rng);
+        let z: F = rng.sample(StandardNormal);
+        self.mu + self.theta * g + self.sigma * g.sqrt() * z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variance_gamma_mean_and_variance() {
+        let (mu, theta, sigma, nu) = (1.0, -0.5, 2.0, 0.3);
+        let d = VarianceGamma::new(mu, theta, sigma, nu).unwrap();
+        let expected_mean = mu + theta;
+        let expected_variance = sigma * sigma + theta * theta * nu;
+        let mut rng = crate::test::rng(261);
+        let mut results = [0.0; 10000];
+        for i in results.iter_mut() {
+            *i = d.sample(&mut rng);
+        }
+        let mean = results.iter().sum::<f64>() / results.len() as f64;
+        assert!((mean - expected_mean).abs() < 0.1);
+        let variance =
+            results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+        assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+    }
+
+    #[test]
+    fn test_variance_gamma_invalid() {
+        assert_eq!(
+            VarianceGamma::new(f64::NAN, 0.0, 1.0, 1.0),
+            Err(Error::LocationNotFinite)
+        );
+        assert_eq!(
+            VarianceGamma::new(0.0, f64::INFINITY, 1.0, 1.0),
+            Err(Error::DriftNotFinite)
+        );
+        assert_eq!(
+            VarianceGamma::new(0.0, 0.0, 0.0, 1.0),
+            Err(Error::SigmaNotPositive)
+        );
+        assert_eq!(
+            VarianceGamma::new(0.0, 0.0, 1.0, -1.0),
+            Err(Error::NuNotPositive)
+        );
+    }
+
+    #[test]
+    fn variance_gamma_distributions_can_be_compared() {
+        assert_eq!(
+            VarianceGamma::new(1.0, 2.0, 3.0, 4.0),
+            VarianceGamma::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Yule–Simon distribution `YuleSimon(ρ)`.
+
+use crate::{Distribution, Exp1};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [Yule–Simon distribution](https://en.wikipedia.org/wiki/Yule%E2%80%93Simon_distribution)
+/// `YuleSimon(ρ)`.
+///
+/// This heavy-tailed discrete distribution on the positive integers is the
+/// limiting distribution of the number of links to a node in preferential
+/// attachment processes, and of the number of species per genus in Yule's
+/// model of evolution. Its tail decays like `k^-(ρ + 1)`, as for the
+/// [`Zeta`](crate::Zeta) distribution with `s = ρ + 1`.
+///
+/// The mean is only finite for `ρ > 1` and the variance only for `ρ > 2`.
+///
+/// # Density function
+///
+/// `f(k) = ρ B(k, ρ + 1)` for `k >= 1`, where `B` is the beta function.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, YuleSimon};
+///
+/// let yule_simon = YuleSimon::new(2.5).unwrap();
+/// let v = yule_simon.sample(&mut rand::rng());
+/// println!("{} is from a Yule-Simon distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// The distribution is the mixture of the geometric distributions on the
+/// positive integers with probability of success `exp(-W)`, where `W` is
+/// exponentially distributed with rate `ρ`. Samples are generated as such,
+/// using the inversion method for the geometric distribution.
+///
+/// Samples exceeding `u64::MAX` saturate to `u64::MAX`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+/// Error type returned from [`YuleSimon::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `rho <= 0`, `rho = ∞` or `nan`.
+    RhoNotPositive,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::RhoNotPositive => "rho is not positive and finite in Yule-Simon distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl YuleSimon {
+    /// Construct a new `YuleSimon` distribution with shape parameter `rho`.
+    pub fn new(rho: f64) -> Result<YuleSimon, Error> {
+        if !(rho > 0.0 && rho.is_finite()) {
+            return Err(Error::RhoNotPositive);
+        }
+        Ok(YuleSimon { rho })
+    }
+
+    /// Returns the shape parameter `rho`.
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+}
+
+impl Distribution<u64> for YuleSimon {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let w: f64 = Exp1.sample(rng);
+        let e: f64 = Exp1.sample(rng);
+        // The geometric distribution with probability of failure
+        // q = 1 - exp(-w/ρ) satisfies P(K > k) = q^k, hence
+        // K = ceil(e / -ln(q)) for standard exponential e.
+        let neg_ln_q = -(-(-w / self.rho).exp()).ln_1p();
+        (e / neg_ln_q).ceil().max(1.0) as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_rho() {
+        for rho in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(YuleSimon::new(rho), Err(Error::RhoNotPositive));
+        }
+        assert!(YuleSimon::new(1e-10).is_ok());
+    }
+
+    #[test]
+    fn test_mean_and_variance() {
+        let mut rng = crate::test::rng(442);
+        for rho in [6.0, 10.0] {
+            let d = YuleSimon::new(rho).unwrap();
+            let expected_mean = rho / (rho - 1.0);
+            let expected_variance = rho * rho / ((rho - 1.0).powi(2) * (rho - 2.0));
+
+            let mut results = [0.0; 10000];
+            for i in results.iter_mut() {
+                *i = d.sample(&mut rng) as f64;
+            }
+            assert!(results.iter().all(|&x| x >= 1.0));
+
+            let mean = results.iter().sum::<f64>() / results.len() as f64;
+            assert!((mean - expected_mean).abs() < expected_mean / 50.0);
+
+            let variance =
+                results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+            assert!((variance - expected_variance).abs() < expected_variance / 5.0);
+        }
+    }
+
+    #[test]
+    fn test_small_rho_saturates() {
+        let d = YuleSimon::new(1e-300).unwrap();
+        let mut rng = crate::test::rng(443);
+        assert_eq!(d.sample(&mut rng), u64::MAX);
+    }
+
+    #[test]
+    fn yule_simon_distributions_can_be_compared() {
+        assert_eq!(YuleSimon::new(1.5), YuleSimon::new(1.5));
+    }
+}
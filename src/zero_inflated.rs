@@ -0,0 +1,420 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Zero-inflated and hurdle count distributions.
+
+use crate::binomial::sample_binomial;
+use crate::poisson::KnuthMethod;
+use crate::uniform::SampleUniform;
+use crate::{
+    Bernoulli, Binomial, Distribution, Exp1, Geometric, Poisson, StandardNormal, StandardUniform,
+};
+use core::fmt;
+use num_traits::{Float, FloatConst, Zero};
+use rand::{Rng, RngExt};
+
+/// Count distributions with a known probability of zero.
+///
+/// This is separate from [`SampleNonZero`], which is implemented for
+/// several sample types of the same distribution.
+pub trait ZeroProbability {
+    /// Returns the probability of sampling zero.
+    ///
+    /// This is computed in `f64`, also for distributions using another
+    /// float type. [`Hurdle`] only calls it on construction, and the
+    /// implementations of [`SampleNonZero::sample_nonzero`] in this crate
+    /// do not call it, so that sampling avoids `f64` arithmetic.
+    fn zero_probability(&self) -> f64;
+}
+
+/// Count distributions which can be sampled conditioned on a non-zero
+/// outcome, i.e. from their zero-truncated distribution.
+///
+/// This is used by [`Hurdle`].
+pub trait SampleNonZero<T>: Distribution<T> + ZeroProbability {
+    /// Generate a random value conditioned on being non-zero.
+    ///
+    /// Implementations are exact and do not degrade to plain rejection
+    /// sampling when [`ZeroProbability::zero_probability`] is close to one.
+    /// The result is unspecified if the probability of zero is one.
+    fn sample_nonzero<R: Rng + ?Sized>(&self, rng: &mut R) -> T;
+}
+
+impl<F> ZeroProbability for Geometric<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn zero_probability(&self) -> f64 {
+        self.p().to_f64().unwrap()
+    }
+}
+
+impl<F> SampleNonZero<u64> for Geometric<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn sample_nonzero<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // The geometric distribution is memoryless
+        self.sample(rng).saturating_add(1)
+    }
+}
+
+impl<F> ZeroProbability for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    fn zero_probability(&self) -> f64 {
+        (-self.lambda().to_f64().unwrap()).exp()
+    }
+}
+
+impl<F> SampleNonZero<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    fn sample_nonzero<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let lambda = self.lambda();
+        if lambda >= F::LN_2() {
+            // At most two attempts are expected
+            loop {
+                let x = self.sample(rng);
+                if x > F::zero() {
+                    return x;
+                }
+            }
+        }
+
+        // Given at least one event of a unit-time Poisson process, the time
+        // `t` of the first event is a truncated exponential, and the number
+        // of further events is Poisson distributed with rate λ (1 - t).
+        let u: F = rng.sample(StandardUniform);
+        let lambda_t = -(u * (-lambda).exp_m1()).ln_1p();
+        let remaining = lambda - lambda_t;
+        if remaining > F::zero() {
+            F::one() + KnuthMethod::new(remaining).sample(rng)
+        } else {
+            F::one()
+        }
+    }
+}
+
+impl<F> SampleNonZero<u64> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+{
+    fn sample_nonzero<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let x: F = self.sample_nonzero(rng);
+        // saturating cast
+        x.to_u64().unwrap_or(u64::MAX)
+    }
+}
+
+impl<F> ZeroProbability for Binomial<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
+    fn zero_probability(&self) -> f64 {
+        (self.n() as f64 * (-self.p().to_f64().unwrap()).ln_1p()).exp()
+    }
+}
+
+impl<F> SampleNonZero<u64> for Binomial<F>
+where
+    F: Float + SampleUniform,
+    StandardUniform: Distribution<F>,
+{
+    fn sample_nonzero<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let (n, p) = (self.n(), self.p());
+        // ln of the probability of zero, in the float type of the distribution
+        let ln_q = (-p).ln_1p();
+        let ln_zero_probability = F::from(n).unwrap() * ln_q;
+        if ln_zero_probability.exp() <= F::from(0.5).unwrap() {
+            // At most two attempts are expected
+            loop {
+                let x = self.sample(rng);
+                if x > 0 {
+                    return x;
+                }
+            }
+        }
+
+        // Given at least one success, the index `k` of the first success
+        // follows a geometric distribution truncated to `1..=n`, and the
+        // number of further successes is binomial in the remaining trials.
+        let u: F = rng.random();
+        let k = ((u * ln_zero_probability.exp_m1()).ln_1p() / ln_q).ceil();
+        let k = k.to_u64().unwrap_or(0).clamp(1, n);
+        1 + sample_binomial(n - k, p, rng)
+    }
+}
+
+/// Error type returned from [`ZeroInflated::new`] and [`Hurdle::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The probability is `nan` or outside `[0, 1]`.
+    InvalidProbability,
+    /// The distribution of non-zero values samples only zero.
+    AlwaysZero,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidProbability => {
+                "probability of zero is NaN or outside [0, 1] in zero-inflated or hurdle distribution"
+            }
+            Error::AlwaysZero => "count distribution of hurdle distribution is always zero",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// A [zero-inflated](https://en.wikipedia.org/wiki/Zero-inflated_model)
+/// count distribution `ZI(π, D)`.
+///
+/// With probability `π` (`pi`) the sample is zero, and otherwise it is a
+/// sample of `D`, which may itself be zero. The total probability of zero is
+/// thus `π + (1 - π) P_D(0)`. This models counts with excess zeros, e.g. the
+/// zero-inflated Poisson distribution with `D = Poisson`.
+///
+/// See [`Hurdle`] for the model where all zeros are produced by the first
+/// stage.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Poisson, ZeroInflated};
+///
+/// let zip = ZeroInflated::new(0.3, Poisson::new(2.5).unwrap()).unwrap();
+/// let v: f64 = zip.sample(&mut rand::rng());
+/// println!("{} is from a zero-inflated Poisson distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZeroInflated<D> {
+    zero: Bernoulli,
+    distr: D,
+}
+
+impl<D> ZeroInflated<D> {
+    /// Construct a new `ZeroInflated` distribution with extra probability
+    /// `pi` of zero (`0 <= pi <= 1`), and count distribution `distr`.
+    pub fn new(pi: f64, distr: D) -> Result<ZeroInflated<D>, Error> {
+        let zero = Bernoulli::new(pi).map_err(|_| Error::InvalidProbability)?;
+        Ok(ZeroInflated { zero, distr })
+    }
+
+    /// Returns a reference to the count distribution.
+    pub fn inner(&self) -> &D {
+        &self.distr
+    }
+}
+
+impl<T, D> Distribution<T> for ZeroInflated<D>
+where
+    T: Zero,
+    D: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        if self.zero.sample(rng) {
+            T::zero()
+        } else {
+            self.distr.sample(rng)
+        }
+    }
+}
+
+/// A [hurdle](https://en.wikipedia.org/wiki/Hurdle_model) count distribution
+/// `Hurdle(π, D)`.
+///
+/// With probability `π` (`pi`) the sample is zero, and otherwise it is a
+/// sample of the zero-truncated distribution of `D`, i.e. of `D` conditioned
+/// on being non-zero. The probability of zero is thus exactly `π`. For
+/// `π = 0` this is the zero-truncated distribution of `D`.
+///
+/// See [`ZeroInflated`] for the model where `D` also contributes zeros.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Hurdle, Poisson};
+///
+/// let hurdle = Hurdle::new(0.6, Poisson::new(0.1).unwrap()).unwrap();
+/// let v: f64 = hurdle.sample(&mut rand::rng());
+/// println!("{} is from a hurdle Poisson distribution", v);
+/// ```
+///
+/// # Notes
+///
+/// Non-zero values are sampled with [`SampleNonZero::sample_nonzero`], which
+/// is efficient even if `D` is almost always zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hurdle<D> {
+    zero: Bernoulli,
+    distr: D,
+}
+
+impl<D> Hurdle<D> {
+    /// Construct a new `Hurdle` distribution with probability `pi` of zero
+    /// (`0 <= pi <= 1`), and count distribution `distr`, which must have a
+    /// non-zero probability of non-zero values.
+    pub fn new(pi: f64, distr: D) -> Result<Hurdle<D>, Error>
+    where
+        D: ZeroProbability,
+    {
+        let zero = Bernoulli::new(pi).map_err(|_| Error::InvalidProbability)?;
+        if !(distr.zero_probability() < 1.0) {
+            return Err(Error::AlwaysZero);
+        }
+        Ok(Hurdle { zero, distr })
+    }
+
+    /// Returns a reference to the count distribution.
+    pub fn inner(&self) -> &D {
+        &self.distr
+    }
+}
+
+impl<T, D> Distribution<T> for Hurdle<D>
+where
+    T: Zero,
+    D: SampleNonZero<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        if self.zero.sample(rng) {
+            T::zero()
+        } else {
+            self.distr.sample_nonzero(rng)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let poisson = Poisson::new(1.0).unwrap();
+        assert_eq!(
+            ZeroInflated::new(-0.1, poisson),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(
+            ZeroInflated::new(f64::NAN, poisson),
+            Err(Error::InvalidProbability)
+        );
+        assert_eq!(Hurdle::new(1.5, poisson), Err(Error::InvalidProbability));
+        assert_eq!(
+            Hurdle::new(0.5, Binomial::new(0, 0.5).unwrap()),
+            Err(Error::AlwaysZero)
+        );
+        assert_eq!(
+            Hurdle::new(0.5, Binomial::new(10, 0.0).unwrap()),
+            Err(Error::AlwaysZero)
+        );
+        assert_eq!(
+            Hurdle::new(0.5, Geometric::new(1.0).unwrap()),
+            Err(Error::AlwaysZero)
+        );
+    }
+
+    #[test]
+    fn sample_nonzero() {
+        let mut rng = crate::test::rng(381);
+        for lambda in [1e-12, 0.01, 0.5, 1.0, 30.0] {
+            let d = Poisson::new(lambda).unwrap();
+            for _ in 0..100 {
+                let (x, k): (f64, u64) = (d.sample_nonzero(&mut rng), d.sample_nonzero(&mut rng));
+                assert!(x >= 1.0);
+                assert!(k >= 1);
+            }
+        }
+        for (n, p) in [(1, 1e-9), (5, 0.01), (100, 0.001), (10, 0.5), (3, 1.0)] {
+            let d = Binomial::new(n, p).unwrap();
+            for _ in 0..100 {
+                assert!((1..=n).contains(&d.sample_nonzero(&mut rng)));
+            }
+        }
+        assert_eq!(Binomial::new(1, 1e-9).unwrap().sample_nonzero(&mut rng), 1);
+        for (n, p) in [(5, 0.01f32), (10, 0.5)] {
+            let d = Binomial::new(n, p).unwrap();
+            for _ in 0..100 {
+                assert!((1..=n).contains(&d.sample_nonzero(&mut rng)));
+            }
+        }
+        for p in [1e-6, 0.5, 0.99] {
+            let d = Geometric::new(p).unwrap();
+            for _ in 0..100 {
+                assert!(d.sample_nonzero(&mut rng) >= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_probability() {
+        assert_almost_eq!(
+            Poisson::new(2.0f32).unwrap().zero_probability(),
+            (-2f64).exp(),
+            1e-7
+        );
+        assert_almost_eq!(
+            Binomial::new(3, 0.5).unwrap().zero_probability(),
+            0.125,
+            1e-15
+        );
+        assert_eq!(Geometric::new(0.25).unwrap().zero_probability(), 0.25);
+    }
+
+    #[test]
+    fn sample() {
+        let mut rng = crate::test::rng(382);
+        let zi = ZeroInflated::new(1.0, Poisson::new(5.0).unwrap()).unwrap();
+        let hurdle = Hurdle::new(0.0, Poisson::new(0.01).unwrap()).unwrap();
+        for _ in 0..100 {
+            let (x, y): (f64, f64) = (zi.sample(&mut rng), hurdle.sample(&mut rng));
+            assert_eq!(x, 0.0);
+            assert!(y >= 1.0);
+            let k: u64 = hurdle.sample(&mut rng);
+            assert!(k >= 1);
+        }
+        assert_eq!(zi.inner(), &Poisson::new(5.0).unwrap());
+
+        let zi = ZeroInflated::new(0.0, Binomial::new(10, 1.0).unwrap()).unwrap();
+        let hurdle = Hurdle::new(1.0, Geometric::new(0.5).unwrap()).unwrap();
+        for _ in 0..100 {
+            assert_eq!(zi.sample(&mut rng), 10);
+            assert_eq!(hurdle.sample(&mut rng), 0);
+        }
+    }
+
+    #[test]
+    fn zero_inflated_distributions_can_be_compared() {
+        let poisson = Poisson::new(1.0).unwrap();
+        assert_eq!(
+            ZeroInflated::new(0.5, poisson),
+            ZeroInflated::new(0.5, poisson)
+        );
+        assert_eq!(Hurdle::new(0.5, poisson), Hurdle::new(0.5, poisson));
+    }
+}
@@ -45,11 +45,12 @@ use rand::{Rng, RngExt, distr::OpenClosed01};
 /// This implementation uses floating-point (FP) logic internally, which can
 /// potentially generate very large samples (exceeding e.g. `u64::MAX`).
 ///
-/// It is *safe* to cast such results to an integer type using `as`
-/// (e.g. `distr.sample(&mut rng) as u64`), since such casts are saturating
-/// (e.g. `2f64.powi(64) as u64 == u64::MAX`). It is up to the user to
-/// determine whether this potential loss of accuracy is acceptable
-/// (this determination may depend on the distribution's parameters).
+/// [`Distribution`] is implemented for `F`, `u64` and `u32`. Integer samples
+/// exceeding the range of the integer type saturate to its maximum value,
+/// as do casts using `as` (e.g. `2f64.powi(64) as u64 == u64::MAX`). It is
+/// up to the user to determine whether this potential loss of accuracy is
+/// acceptable (this determination may depend on the distribution's
+/// parameters).
 ///
 /// # Notes
 ///
@@ -143,6 +144,32 @@ where
     }
 }
 
+// Implemented for each float type, since a generic implementation would
+// overlap with `Distribution<F>` for `F = u64`.
+macro_rules! impl_integer_distribution {
+    ($($f:ty),*) => {$(
+        impl Distribution<u64> for Zeta<$f> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+                let x: $f = self.sample(rng);
+                // saturating cast
+                x as u64
+            }
+        }
+
+        impl Distribution<u32> for Zeta<$f> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+                let x: $f = self.sample(rng);
+                // saturating cast
+                x as u32
+            }
+        }
+    )*};
+}
+
+impl_integer_distribution!(f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,7 +201,7 @@ mod tests {
         let d = Zeta::new(a).unwrap();
         let mut rng = crate::test::rng(1);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1.);
         }
     }
@@ -185,7 +212,7 @@ mod tests {
         let d = Zeta::new(a).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1.);
         }
     }
@@ -196,6 +223,25 @@ mod tests {
         test_samples(Zeta::new(2.0).unwrap(), 0f64, &[2.0, 1.0, 1.0, 1.0]);
     }
 
+    #[test]
+    fn zeta_integer_samples() {
+        let d = Zeta::new(2.0).unwrap();
+        let (mut rng_f, mut rng_u64) = (crate::test::rng(493), crate::test::rng(493));
+        for _ in 0..100 {
+            let x: f64 = d.sample(&mut rng_f);
+            let k: u64 = d.sample(&mut rng_u64);
+            assert_eq!(x as u64, k);
+        }
+
+        // very heavy tails saturate
+        let d = Zeta::new(1. + 1e-15).unwrap();
+        let mut rng = crate::test::rng(494);
+        let saturated = (0..100)
+            .filter(|_| Distribution::<u32>::sample(&d, &mut rng) == u32::MAX)
+            .count();
+        assert!(saturated > 0);
+    }
+
     #[test]
     fn zeta_distributions_can_be_compared() {
         assert_eq!(Zeta::new(1.0), Zeta::new(1.0));
@@ -41,10 +41,15 @@ use rand::{Rng, RngExt};
 ///
 /// # Integer vs FP return type
 ///
-/// This implementation uses floating-point (FP) logic internally. It may be
-/// expected that the samples are no greater than `n`, thus it is reasonable to
-/// cast generated samples to any integer type which can also represent `n`
-/// (e.g. `distr.sample(&mut rng) as u64`).
+/// This implementation uses floating-point (FP) logic internally, and
+/// implements [`Distribution`] for `F`, `u64` and `u32`. The samples are no
+/// greater than `n`, thus integer samples are exact if the integer type can
+/// represent `n`, and saturate to its maximum value otherwise.
+///
+/// Since `F` cannot represent all integers above `2^53` (for `f64`), very
+/// large ranks are restricted to the representable values. See
+/// [`ZipfMandelbrot`](crate::ZipfMandelbrot) for a distribution with exact
+/// integer samples up to `u64::MAX`.
 ///
 /// # Implementation details
 ///
@@ -136,8 +141,20 @@ where
     /// Inverse cumulative density function
     #[inline]
     fn inv_cdf(&self, p: F) -> F {
+        self.inv_envelope(p * self.t)
+    }
+
+    /// Total mass of the envelope density, which is `1` on `[0, 1]` and
+    /// `x^-s` on `[1, n]`.
+    #[inline]
+    pub(crate) fn envelope_mass(&self) -> F {
+        self.t
+    }
+
+    /// Inverse of the (unnormalized) cumulative envelope mass
+    #[inline]
+    pub(crate) fn inv_envelope(&self, pt: F) -> F {
         let one = F::one();
-        let pt = p * self.t;
         if pt <= one {
             pt
         } else if self.s != one {
@@ -172,6 +189,32 @@ where
     }
 }
 
+// Implemented for each float type, since a generic implementation would
+// overlap with `Distribution<F>` for `F = u64`.
+macro_rules! impl_integer_distribution {
+    ($($f:ty),*) => {$(
+        impl Distribution<u64> for Zipf<$f> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+                let x: $f = self.sample(rng);
+                // saturating cast
+                x as u64
+            }
+        }
+
+        impl Distribution<u32> for Zipf<$f> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+                let x: $f = self.sample(rng);
+                // saturating cast
+                x as u32
+            }
+        }
+    )*};
+}
+
+impl_integer_distribution!(f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,7 +251,7 @@ mod tests {
         let d = Zipf::new(10., 0.5).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1. && r <= 10.0);
         }
     }
@@ -218,7 +261,7 @@ mod tests {
         let d = Zipf::new(10., 1.).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1. && r <= 10.0);
         }
     }
@@ -228,7 +271,7 @@ mod tests {
         let d = Zipf::new(10., 0.).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1. && r <= 10.0);
         }
         // TODO: verify that this is a uniform distribution
@@ -239,7 +282,7 @@ mod tests {
         let d = Zipf::new(10., f64::infinity()).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r == 1.);
         }
     }
@@ -249,7 +292,7 @@ mod tests {
         let d = Zipf::new(f64::MAX, 1.5).unwrap();
         let mut rng = crate::test::rng(2);
         for _ in 0..1000 {
-            let r = d.sample(&mut rng);
+            let r: f64 = d.sample(&mut rng);
             assert!(r >= 1. && r <= f64::MAX);
         }
         // TODO: verify that this is a zeta distribution
@@ -261,6 +304,23 @@ mod tests {
         test_samples(Zipf::new(10., 2.0).unwrap(), 0f64, &[1.0, 2.0, 3.0, 2.0]);
     }
 
+    #[test]
+    fn zipf_integer_samples() {
+        let d = Zipf::new(10f32, 1.5).unwrap();
+        let (mut rng_f, mut rng_u32) = (crate::test::rng(495), crate::test::rng(495));
+        for _ in 0..100 {
+            let x: f32 = d.sample(&mut rng_f);
+            let k: u32 = d.sample(&mut rng_u32);
+            assert_eq!(x as u32, k);
+            assert!((1..=10).contains(&k));
+        }
+
+        let d = Zipf::new(1e12, 0.0).unwrap();
+        let mut rng = crate::test::rng(496);
+        let k: u64 = d.sample(&mut rng);
+        assert!((1..=1_000_000_000_000).contains(&k));
+    }
+
     #[test]
     fn zipf_distributions_can_be_compared() {
         assert_eq!(Zipf::new(1.0, 2.0), Zipf::new(1.0, 2.0));
@@ -0,0 +1,267 @@
+// Copyright 2026 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Zipf–Mandelbrot distribution.
+
+use crate::{Distribution, Zipf};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::{Rng, RngExt};
+
+/// The [Zipf–Mandelbrot distribution](https://en.wikipedia.org/wiki/Zipf%E2%80%93Mandelbrot_law)
+/// `ZipfMandelbrot(n, s, q)`.
+///
+/// This generalization of the [`Zipf`] distribution on the ranks
+/// `1, 2, ..., n` has probabilities proportional to `1 / (k + q)^s`. The
+/// offset `q` flattens the head of the distribution, which better fits e.g.
+/// word frequencies and the popularity of cache keys. For `q = 0` it is
+/// `Zipf(n, s)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, ZipfMandelbrot};
+///
+/// let keys = ZipfMandelbrot::new(1_000_000, 1.1, 2.7).unwrap();
+/// let key = keys.sample(&mut rand::rng());
+/// println!("key {} was requested", key);
+/// ```
+///
+/// # Notes
+///
+/// The samples are integers, in contrast to [`Zipf`]. They are generated
+/// with the rejection sampler of [`Zipf`], applied after the change of
+/// variables `x ↦ (x + q) / (1 + q)` which turns the offset law into a
+/// shifted Zipf law.
+///
+/// The change of variables scales the ranks by `1 / (1 + q)`, which leaves
+/// about `2^52 / (k + q)` values of `f64` per rank `k`. Therefore the offset
+/// is limited to `q <= 2^40`, where the probabilities are still resolved to
+/// a relative precision of about `2^-12`.
+///
+/// Ranks above `2^53`, where `f64` cannot represent every integer, are
+/// chosen uniformly among the ranks which round to the same `f64` value,
+/// so that every rank up to `n` can be sampled. This neglects the variation
+/// of the probabilities among them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZipfMandelbrot {
+    n: u64,
+    s: f64,
+    q: f64,
+    // q / (1 + q), the start of the support after the change of variables
+    shift: f64,
+    zipf: Zipf<f64>,
+}
+
+/// Error type returned from [`ZipfMandelbrot::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `n = 0`.
+    NTooSmall,
+    /// `s < 0` or `nan`.
+    STooSmall,
+    /// `q <= -1`, `q > 2^40` or `nan`.
+    QInvalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::NTooSmall => "n = 0 in Zipf-Mandelbrot distribution",
+            Error::STooSmall => "s < 0 or is NaN in Zipf-Mandelbrot distribution",
+            Error::QInvalid => "q <= -1, q > 2^40 or q is NaN in Zipf-Mandelbrot distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Largest supported offset `q`
+const Q_MAX: f64 = (1u64 << 40) as f64;
+
+impl ZipfMandelbrot {
+    /// Construct a new `ZipfMandelbrot` distribution for a set with `n`
+    /// elements, a frequency rank exponent `s` and a rank offset `q`.
+    pub fn new(n: u64, s: f64, q: f64) -> Result<ZipfMandelbrot, Error> {
+        if n == 0 {
+            return Err(Error::NTooSmall);
+        }
+        if !(s >= 0.0) {
+            return Err(Error::STooSmall);
+        }
+        if !(q > -1.0 && q <= Q_MAX) {
+            return Err(Error::QInvalid);
+        }
+        let zipf = Zipf::new((n as f64 + q) / (1.0 + q), s).unwrap();
+        Ok(ZipfMandelbrot {
+            n,
+            s,
+            q,
+            shift: q / (1.0 + q),
+            zipf,
+        })
+    }
+
+    /// Returns the number of elements `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the frequency rank exponent `s`.
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// Returns the rank offset `q`.
+    pub fn q(&self) -> f64 {
+        self.q
+    }
+}
+
+impl Distribution<u64> for ZipfMandelbrot {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // Below 2^53 all integers are representable by f64.
+        const EXACT_LIMIT: f64 = (1u64 << 53) as f64;
+
+        let mass = self.zipf.envelope_mass() - self.shift;
+        let n = self.n as f64;
+        loop {
+            // The envelope of the Zipf distribution, restricted to
+            // [shift, (n + q) / (1 + q)], in the transformed variable w.
+            let u: f64 = rng.random();
+            let w = self.zipf.inv_envelope(self.shift + u * mass);
+            // w - shift = (x - 1) / (1 + q), without cancellation in x
+            let x = ((w - self.shift) * (1.0 + self.q) + 1.0)
+                .floor()
+                .clamp(1.0, n);
+            let w_x = (x + self.q) / (1.0 + self.q);
+            let ratio = (w.max(1.0) / w_x).powf(self.s);
+
+            let y: f64 = rng.random();
+            if y < ratio {
+                if x < EXACT_LIMIT {
+                    return x as u64;
+                }
+                let (low, high) = rounding_interval(x, self.n);
+                return rng.random_range(low..=high);
+            }
+        }
+    }
+}
+
+/// The ranks in `1..=n` which round to `x >= 2^53`, as an inclusive range.
+fn rounding_interval(x: f64, n: u64) -> (u64, u64) {
+    // Spacings of f64 below and above x, which differ at powers of two
+    let below = (x - f64::from_bits(x.to_bits() - 1)) as u128;
+    let above = (f64::from_bits(x.to_bits() + 1) - x) as u128;
+    // x may be 2^64, which does not fit u64
+    let x = x as u128;
+    let low = x - below / 2;
+    let high = (x + above / 2 - 1).min(n as u128);
+    (low as u64, high as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(ZipfMandelbrot::new(0, 1.0, 0.0), Err(Error::NTooSmall));
+        for s in [-1.0, f64::NAN] {
+            assert_eq!(ZipfMandelbrot::new(10, s, 0.0), Err(Error::STooSmall));
+        }
+        for q in [-1.0, -2.0, 1e13, f64::INFINITY, f64::NAN] {
+            assert_eq!(ZipfMandelbrot::new(10, 1.0, q), Err(Error::QInvalid));
+        }
+        let d = ZipfMandelbrot::new(10, 1.5, -0.5).unwrap();
+        assert_eq!((d.n(), d.s(), d.q()), (10, 1.5, -0.5));
+    }
+
+    #[test]
+    fn test_sample_range() {
+        let mut rng = crate::test::rng(481);
+        for (n, s, q) in [
+            (1, 1.0, 0.0),
+            (10, 0.0, 5.0),
+            (10, 2.0, 100.0),
+            (10, 1.0, -0.9),
+        ] {
+            let d = ZipfMandelbrot::new(n, s, q).unwrap();
+            for _ in 0..1000 {
+                let k = d.sample(&mut rng);
+                assert!((1..=n).contains(&k));
+            }
+        }
+
+        let d = ZipfMandelbrot::new(10, f64::INFINITY, 3.0).unwrap();
+        for _ in 0..100 {
+            assert_eq!(d.sample(&mut rng), 1);
+        }
+    }
+
+    #[test]
+    fn test_large_ranks() {
+        // For s = 0 the distribution is uniform, and ranks beyond 2^53 must
+        // not be restricted to values representable by f64.
+        let mut rng = crate::test::rng(482);
+        let d = ZipfMandelbrot::new(u64::MAX, 0.0, 0.0).unwrap();
+        let mut odd = 0;
+        for _ in 0..1000 {
+            let k = d.sample(&mut rng);
+            assert!(k >= 1);
+            odd += k % 2;
+        }
+        assert!((400..600).contains(&odd));
+    }
+
+    #[test]
+    fn test_large_offset() {
+        // The probabilities of 10 ranks differ by less than 1e-10.
+        let mut rng = crate::test::rng(483);
+        let d = ZipfMandelbrot::new(10, 1.0, Q_MAX).unwrap();
+        let mut counts = [0; 10];
+        for _ in 0..10000 {
+            counts[d.sample(&mut rng) as usize - 1] += 1;
+        }
+        for count in counts {
+            assert!((900..1100).contains(&count));
+        }
+    }
+
+    #[test]
+    fn test_rounding_interval() {
+        // consecutive values cover every rank exactly once, also across
+        // powers of two
+        let mut x = f64::from_bits(((1u64 << 54) as f64).to_bits() - 3);
+        let mut next_low = rounding_interval(x, u64::MAX).0;
+        for _ in 0..6 {
+            let (low, high) = rounding_interval(x, u64::MAX);
+            assert_eq!(low, next_low);
+            assert!(low <= high);
+            next_low = high + 1;
+            x = f64::from_bits(x.to_bits() + 1);
+        }
+
+        // the interval of the largest value is clamped to n
+        let n = u64::MAX;
+        assert_eq!(rounding_interval(n as f64, n), (n - 1023, n));
+        let n = (1u64 << 60) + 1;
+        assert_eq!(rounding_interval(n as f64, n), (n - 65, n));
+    }
+
+    #[test]
+    fn zipf_mandelbrot_distributions_can_be_compared() {
+        assert_eq!(
+            ZipfMandelbrot::new(10, 1.0, 2.0),
+            ZipfMandelbrot::new(10, 1.0, 2.0)
+        );
+    }
+}